@@ -0,0 +1,41 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use division_math::{Matrix4x4, Vector3, Vector4};
+
+fn random_float() -> f32 {
+    (rand::random::<f32>() + 1.) * 10000.
+}
+
+fn vector_accumulate(set: &[Vector4]) -> Vector4 {
+    let mut acc = Vector4::zero();
+
+    for i in 0..set.len() - 4 {
+        acc = acc + (set[i] / set[i + 1]) + (set[i + 2] / set[i + 3].w) - set[i];
+    }
+
+    acc
+}
+
+fn matrix_accumulate(set: &[Matrix4x4]) -> Matrix4x4 {
+    let mut m = Matrix4x4::identity();
+
+    for el in set {
+        m = *el * m * el.inverse();
+    }
+
+    m
+}
+
+fn bench_ops(c: &mut Criterion) {
+    let vectors: Vec<Vector4> = (0..100000)
+        .map(|_| Vector4::new(random_float(), random_float(), random_float(), random_float()))
+        .collect();
+    let matrices: Vec<Matrix4x4> = (0..10000)
+        .map(|_| Matrix4x4::scale(Vector3::new(random_float(), random_float(), random_float())))
+        .collect();
+
+    c.bench_function("vector4_accumulate", |b| b.iter(|| vector_accumulate(black_box(&vectors))));
+    c.bench_function("matrix4x4_accumulate", |b| b.iter(|| matrix_accumulate(black_box(&matrices))));
+}
+
+criterion_group!(benches, bench_ops);
+criterion_main!(benches);
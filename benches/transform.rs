@@ -0,0 +1,57 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use division_math::{
+    transform_points, transform_points_perspective, transform_vec4s, transform_vectors, Matrix4x4,
+    Vector3, Vector4,
+};
+
+fn random_vector3s(count: usize) -> Vec<Vector3> {
+    (0..count)
+        .map(|_| Vector3::new(rand::random(), rand::random(), rand::random()))
+        .collect()
+}
+
+fn matrix() -> Matrix4x4 {
+    Matrix4x4::translation(Vector3::new(1., 2., 3.)) * Matrix4x4::scale(Vector3::new(0.5, 2., 1.5))
+}
+
+fn bench_transform(c: &mut Criterion) {
+    let m = matrix();
+    let mut group = c.benchmark_group("transform");
+
+    for count in [64, 4096, 65536] {
+        let points = random_vector3s(count);
+        let vec4s: Vec<Vector4> = points.iter().map(|p| p.to_vec4_as_point()).collect();
+        let mut dst = vec![Vector3::zero(); count];
+        let mut dst4 = vec![Vector4::zero(); count];
+
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_with_input(BenchmarkId::new("per_element", count), &points, |b, points| {
+            b.iter(|| {
+                let m = *black_box(&m);
+
+                for (p, d) in points.iter().zip(dst.iter_mut()) {
+                    let r = m * p.to_vec4_as_point();
+                    *d = Vector3::new(r.x, r.y, r.z);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("points", count), &points, |b, points| {
+            b.iter(|| transform_points(black_box(&m), points, &mut dst))
+        });
+        group.bench_with_input(BenchmarkId::new("points_perspective", count), &points, |b, points| {
+            b.iter(|| transform_points_perspective(black_box(&m), points, &mut dst))
+        });
+        group.bench_with_input(BenchmarkId::new("vectors", count), &points, |b, points| {
+            b.iter(|| transform_vectors(black_box(&m), points, &mut dst))
+        });
+        group.bench_with_input(BenchmarkId::new("vec4s", count), &vec4s, |b, vec4s| {
+            b.iter(|| transform_vec4s(black_box(&m), vec4s, &mut dst4))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_transform);
+criterion_main!(benches);
@@ -10,84 +10,280 @@ struct FuncArg {
     type_name: String,
 }
 
+#[derive(Clone, Copy)]
+struct VecKind {
+    prefix: &'static str,
+    scalar: &'static str,
+    // Padded to 4 lanes with a hidden `w` that stays 0, ops run as the matching Vector4 op.
+    aligned: bool,
+}
+
+impl VecKind {
+    fn vec(&self, dim: usize) -> String {
+        let suffix = if self.aligned { "A" } else { "" };
+        format!("{}Vector{dim}{suffix}", self.prefix)
+    }
+
+    fn mat(&self) -> String {
+        format!("{}Matrix4x4", self.prefix)
+    }
+
+    fn is_f32(&self) -> bool {
+        self.scalar == "f32"
+    }
+
+    fn is_float(&self) -> bool {
+        self.scalar.starts_with('f')
+    }
+
+    fn is_signed(&self) -> bool {
+        !self.scalar.starts_with('u')
+    }
+
+    fn approx_fn(&self) -> &'static str {
+        if self.is_f32() {
+            "approx"
+        } else {
+            "approx_f64"
+        }
+    }
+}
+
+const F32_KIND: VecKind = VecKind {
+    prefix: "",
+    scalar: "f32",
+    aligned: false,
+};
+
+const F64_KIND: VecKind = VecKind {
+    prefix: "D",
+    scalar: "f64",
+    aligned: false,
+};
+
+const I32_KIND: VecKind = VecKind {
+    prefix: "I",
+    scalar: "i32",
+    aligned: false,
+};
+
+const U32_KIND: VecKind = VecKind {
+    prefix: "U",
+    scalar: "u32",
+    aligned: false,
+};
+
+// Only used for Vector3A.
+const F32A_KIND: VecKind = VecKind {
+    prefix: "",
+    scalar: "f32",
+    aligned: true,
+};
+
+const FLOAT_KINDS: [VecKind; 2] = [F32_KIND, F64_KIND];
+const INT_KINDS: [VecKind; 2] = [I32_KIND, U32_KIND];
+const ALL_KINDS: [VecKind; 4] = [F32_KIND, F64_KIND, I32_KIND, U32_KIND];
+
 const VEC_FIELDS: [&str; 4] = ["x", "y", "z", "w"];
+const COLOR_FIELDS: [&str; 4] = ["r", "g", "b", "a"];
+
+const ARITHMETIC_OPS: [(&str, &str); 5] = [
+    ("Add", "+"),
+    ("Sub", "-"),
+    ("Mul", "*"),
+    ("Div", "/"),
+    ("Rem", "%"),
+];
+
+const BITWISE_OPS: [(&str, &str); 3] = [("BitAnd", "&"), ("BitOr", "|"), ("BitXor", "^")];
 
 fn main() {
+    // The float functions come from std or libm, without either every call site would fail.
+    if env::var_os("CARGO_FEATURE_STD").is_none() && env::var_os("CARGO_FEATURE_LIBM").is_none() {
+        println!("cargo::error=division_math needs the `libm` feature when `std` is disabled");
+        return;
+    }
+
     let out_dir = env::var_os("OUT_DIR").unwrap();
-    let swizzle_gen_path = Path::new(&out_dir).join("vector.swizzling.gen.rs");
+    let swizzle_gen_dir = Path::new(&out_dir).join("swizzle");
     let math_gen_path = Path::new(&out_dir).join("vector.math.gen.rs");
-
-    fs::write(&swizzle_gen_path, create_vector_fields_swizzles()).unwrap();
+    let matrix_gen_path = Path::new(&out_dir).join("matrix.gen.rs");
+    let half_gen_path = Path::new(&out_dir).join("half_vector.gen.rs");
+    let wide_gen_path = Path::new(&out_dir).join("wide.gen.rs");
+    let serde_gen_path = Path::new(&out_dir).join("serde.gen.rs");
+    let cast_gen_path = Path::new(&out_dir).join("cast.gen.rs");
+    let interop_gen_path = Path::new(&out_dir).join("interop.gen.rs");
+    let aligned_gen_path = Path::new(&out_dir).join("vector3a.gen.rs");
+
+    fs::create_dir_all(&swizzle_gen_dir).unwrap();
+    let swizzle_gen_paths = create_vector_fields_swizzles()
+        .into_iter()
+        .map(|(ty, code)| {
+            let path = swizzle_gen_dir.join(format!("{}.gen.rs", ty.to_lowercase()));
+            fs::write(&path, code).unwrap();
+            path
+        })
+        .collect::<Vec<_>>();
     fs::write(&math_gen_path, create_vec_math()).unwrap();
+    fs::write(&matrix_gen_path, create_matrices()).unwrap();
+    fs::write(&half_gen_path, create_half_vectors()).unwrap();
+    fs::write(&wide_gen_path, create_wide_vectors()).unwrap();
+    fs::write(&serde_gen_path, create_serde_impls()).unwrap();
+    fs::write(&cast_gen_path, create_cast_impls()).unwrap();
+    fs::write(&interop_gen_path, create_interop_impls()).unwrap();
+    fs::write(&aligned_gen_path, create_aligned_vec_math()).unwrap();
 
     let _ = Command::new("rustfmt")
         .arg(math_gen_path.to_str().unwrap())
-        .arg(swizzle_gen_path.to_str().unwrap())
+        .args(&swizzle_gen_paths)
+        .arg(matrix_gen_path.to_str().unwrap())
+        .arg(half_gen_path.to_str().unwrap())
+        .arg(wide_gen_path.to_str().unwrap())
+        .arg(serde_gen_path.to_str().unwrap())
+        .arg(cast_gen_path.to_str().unwrap())
+        .arg(interop_gen_path.to_str().unwrap())
+        .arg(aligned_gen_path.to_str().unwrap())
         .spawn();
 
+    println!("cargo:rustc-check-cfg=cfg(simd_backend)");
+    if has_simd_backend() {
+        println!("cargo:rustc-cfg=simd_backend");
+    }
+
     println!("cargo:rerun-if-changed=build.rs");
 }
 
-fn create_vector_fields_swizzles() -> String {
-    let vec_dims = [2, 3, 4];
-    let mut str = String::new();
-    str.write_str("/*** This code was generated automatically ***/\n\n")
-        .unwrap();
+// `simd_backend` is set when `enable_simd` has a backend for the target. The `*_simd` call
+// sites are gated on it, so a target without one only fails on the `compile_error!` in
+// src/simd/mod.rs instead of on every missing function.
+fn has_simd_backend() -> bool {
+    let target_features = env::var("CARGO_CFG_TARGET_FEATURE").unwrap_or_default();
+    let has_feature = |name: &str| target_features.split(',').any(|f| f == name);
+    let is_wasm32 = env::var("CARGO_CFG_TARGET_ARCH").is_ok_and(|arch| arch == "wasm32");
+
+    env::var_os("CARGO_FEATURE_ENABLE_SIMD").is_some()
+        && (env::var_os("CARGO_FEATURE_PORTABLE_SIMD").is_some()
+            || has_feature("neon")
+            || (is_wasm32 && has_feature("simd128")))
+}
 
-    for impl_dim in vec_dims {
-        str.write_fmt(format_args!("impl Vector{} {{\n", impl_dim))
+// One file per vector type so the swizzle sets can be switched independently of the math code.
+// Read swizzles come from `swizzle` (non-repeating) or `swizzle_full` (every combination).
+fn create_vector_fields_swizzles() -> Vec<(String, String)> {
+    let vec_dims = [2, 3, 4];
+    let full = env::var_os("CARGO_FEATURE_SWIZZLE_FULL").is_some();
+    let non_repeating = env::var_os("CARGO_FEATURE_SWIZZLE").is_some();
+    let setters = env::var_os("CARGO_FEATURE_SWIZZLE_SETTERS").is_some();
+    let color_swizzles = env::var_os("CARGO_FEATURE_COLOR_SWIZZLES").is_some();
+    let mut files = Vec::new();
+
+    for (kind, impl_dim) in iproduct!(ALL_KINDS, vec_dims) {
+        let mut str = String::new();
+        str.write_str("/*** This code was generated automatically ***/\n\n")
+            .unwrap();
+        str.write_fmt(format_args!("impl {} {{\n", kind.vec(impl_dim)))
             .unwrap();
 
-        for (f0, f1) in iproduct!(0..impl_dim, 0..impl_dim) {
-            let fields = [f0, f1];
+        let read_swizzles = if full {
+            swizzle_combinations(impl_dim)
+        } else if non_repeating {
+            swizzle_permutations(impl_dim)
+        } else {
+            Vec::new()
+        };
 
-            str.write_str(fields_indices_to_function(&fields).as_str())
+        for fields in read_swizzles {
+            str.write_str(fields_indices_to_function(kind, &fields, &VEC_FIELDS).as_str())
                 .unwrap();
-        }
 
-        for (f0, f1, f2) in iproduct!(0..impl_dim, 0..impl_dim, 0..impl_dim) {
-            let fields = [f0, f1, f2];
-
-            str.write_str(fields_indices_to_function(&fields).as_str())
-                .unwrap();
+            if color_swizzles {
+                str.write_str(fields_indices_to_function(kind, &fields, &COLOR_FIELDS).as_str())
+                    .unwrap();
+            }
         }
 
-        for (f0, f1, f2, f3) in iproduct!(0..impl_dim, 0..impl_dim, 0..impl_dim, 0..impl_dim) {
-            let fields = [f0, f1, f2, f3];
-
-            str.write_str(fields_indices_to_function(&fields).as_str())
-                .unwrap();
+        if setters {
+            for len in 1..=impl_dim {
+                for fields in (0..impl_dim).permutations(len) {
+                    str.write_str(fields_indices_to_setters(kind, impl_dim, &fields).as_str())
+                        .unwrap();
+                }
+            }
         }
 
         str.write_str("}\n").unwrap();
+        files.push((kind.vec(impl_dim), str));
     }
 
-    str
+    files
 }
 
-fn fields_indices_to_function(fields: &[i32]) -> String {
-    let vec_fields = ["x", "y", "z", "w"];
+// Every 2, 3 and 4 component selection without repeated fields.
+fn swizzle_permutations(dim: usize) -> Vec<Vec<usize>> {
+    (2..=dim).flat_map(|len| (0..dim).permutations(len)).collect()
+}
+
+// Every 2, 3 and 4 component selection of the first `dim` fields, repeats included.
+fn swizzle_combinations(dim: usize) -> Vec<Vec<usize>> {
+    (2..=4)
+        .flat_map(|len| (0..len).map(|_| 0..dim).multi_cartesian_product())
+        .collect()
+}
 
-    let output_vec_size = fields.len();
+fn fields_indices_to_function(kind: VecKind, fields: &[usize], names: &[&str]) -> String {
+    let output_type = kind.vec(fields.len());
     let func_name = fields
         .iter()
-        .map(|f| vec_fields[*f as usize].to_string())
+        .map(|f| names[*f].to_string())
         .collect::<Vec<String>>()
         .join("");
 
     let args = fields
         .iter()
-        .map(|f| format!("self.{}", vec_fields[*f as usize]))
+        .map(|f| format!("self.{}", VEC_FIELDS[*f]))
         .collect::<Vec<String>>()
         .join(",");
 
     format_args!(
-        "\t#[inline(always)] pub fn {func_name}(self) -> Vector{output_vec_size} 
-        {{ Vector{output_vec_size}::new({args}) }}\n",
+        "\t#[inline(always)] pub fn {func_name}(self) -> {output_type}
+        {{ {output_type}::new({args}) }}\n",
     )
     .to_string()
 }
 
+// `with_*` for every non-repeating swizzle, `set_*` for those writing two or more fields.
+fn fields_indices_to_setters(kind: VecKind, impl_dim: usize, fields: &[usize]) -> String {
+    let ty = kind.vec(impl_dim);
+    let scalar = kind.scalar;
+    let suffix = fields.iter().map(|f| VEC_FIELDS[*f]).join("");
+
+    if fields.len() == 1 {
+        return format!(
+            "\t#[inline(always)] pub fn with_{suffix}(self, {suffix}: {scalar}) -> {ty}
+            {{ {ty} {{ {suffix}, ..self }} }}\n"
+        );
+    }
+
+    let value_ty = kind.vec(fields.len());
+    let assigns = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| format!("self.{} = v.{};", VEC_FIELDS[*f], VEC_FIELDS[i]))
+        .join(" ");
+    let inits = fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| format!("{}: v.{}", VEC_FIELDS[*f], VEC_FIELDS[i]))
+        .join(", ");
+    let rest = if fields.len() < impl_dim { ", ..self" } else { "" };
+
+    format!(
+        "\t#[inline(always)] pub fn set_{suffix}(&mut self, v: {value_ty}) {{ {assigns} }}
+        #[inline(always)] pub fn with_{suffix}(self, v: {value_ty}) -> {ty}
+        {{ {ty} {{ {inits}{rest} }} }}\n"
+    )
+}
+
 fn create_vec_math() -> String {
     let mut result = String::new();
     let vec_dims = [2, 3, 4];
@@ -96,76 +292,365 @@ fn create_vec_math() -> String {
         .write_str("/*** This code was generated automatically ***/\n\n")
         .unwrap();
 
+    for (kind, dim) in iproduct!(FLOAT_KINDS, vec_dims) {
+        result.write_str(create_float_vec(kind, dim).as_str()).unwrap();
+    }
+
+    for (kind, dim) in iproduct!(INT_KINDS, vec_dims) {
+        result.write_str(create_int_vec(kind, dim).as_str()).unwrap();
+    }
+
     for dim in vec_dims {
-        let vec_type = format!("Vector{dim}");
+        result.write_str(create_bool_vec(dim).as_str()).unwrap();
+    }
 
-        result
-            .write_fmt(format_args!("impl {vec_type} {{\n\n"))
-            .unwrap();
-        result
-            .write_str(
-                create_expanded_math_func(
-                    "lerp",
-                    dim,
-                    &[FuncArg {
-                        name: "t".to_string(),
-                        type_name: "f32".to_string(),
-                    }],
-                )
-                .as_str(),
+    result
+        .write_str(create_conversion_impls("Color", "f32", &COLOR_FIELDS).as_str())
+        .unwrap();
+
+    for (kind, dim) in iproduct!(ALL_KINDS, vec_dims) {
+        result.write_str(create_compare_funcs(kind, dim).as_str()).unwrap();
+    }
+
+    result
+}
+
+fn create_float_vec(kind: VecKind, dim: usize) -> String {
+    let mut result = String::new();
+    let vec_type = kind.vec(dim);
+
+    if !kind.is_f32() {
+        result.write_str(create_struct_def(kind, dim).as_str()).unwrap();
+    }
+
+    result
+        .write_fmt(format_args!("impl {vec_type} {{\n\n"))
+        .unwrap();
+    result
+        .write_str(
+            create_expanded_math_func(
+                kind,
+                "lerp",
+                dim,
+                &[FuncArg {
+                    name: "t".to_string(),
+                    type_name: kind.scalar.to_string(),
+                }],
             )
-            .unwrap();
+            .as_str(),
+        )
+        .unwrap();
 
-        for s in [
-            create_new_func(dim),
-            create_all_func(dim),
-            create_zero_func(dim),
-            create_one_func(dim),
-            create_length_funcs(dim),
-            create_approx_func(dim),
-            create_normalized_func(dim),
-            create_ptr_funcs(dim),
-            create_clamp_funcs(dim),
-        ] {
-            result.write_str(s.as_str()).unwrap();
-        }
+    for s in [
+        create_new_func(kind, dim),
+        create_all_func(kind, dim),
+        create_zero_func(kind, dim),
+        create_one_func(kind, dim),
+        create_vec_consts(kind, dim),
+        create_dot_func(kind, dim),
+        create_cross_func(kind, dim),
+        create_length_funcs(kind, dim),
+        create_approx_func(kind, dim),
+        create_normalized_func(kind, dim),
+        create_ptr_funcs(kind, dim),
+        create_clamp_funcs(kind, dim),
+        create_packing_funcs(kind, dim),
+        create_precision_funcs(kind, dim),
+        create_rounding_cast_funcs(kind, dim),
+    ] {
+        result.write_str(s.as_str()).unwrap();
+    }
 
-        result.write_str("\n}\n").unwrap();
-
-        for s in [
-            create_component_wise_vec_func_simd("Add", "+", dim),
-            create_component_wise_vec_func_simd("Sub", "-", dim),
-            create_component_wise_vec_func_simd("Mul", "*", dim),
-            create_component_wise_vec_func_simd("Div", "/", dim),
-            create_component_wise_vec_func("Rem", "%", dim),
-            create_scalar_vec_func_simd("Mul", "*", dim),
-            create_scalar_vec_func_simd("Div", "/", dim),
-            create_scalar_vec_func("Rem", "%", dim),
-            create_typed_op_assign_func("AddAssign", "add_assign", "+", "Self", dim),
-            create_typed_op_assign_func("SubAssign", "sub_assign", "-", "Self", dim),
-            create_op_assign_funcs("MulAssign", "mul_assign", "*", dim),
-            create_op_assign_funcs("DivAssign", "div_assign", "/", dim),
-            create_op_assign_funcs("RemAssign", "rem_assign", "%", dim),
-            create_vec_index_func(dim),
-            create_vec_index_mut_func(dim),
-            create_neg_func(dim),
-            create_from_tuple_impl(dim),
-        ] {
-            result.write_str(s.as_str()).unwrap();
-        }
+    result.write_str("\n}\n").unwrap();
+
+    for s in [
+        create_component_wise_vec_func_simd(kind, "Add", "+", dim),
+        create_component_wise_vec_func_simd(kind, "Sub", "-", dim),
+        create_component_wise_vec_func_simd(kind, "Mul", "*", dim),
+        create_component_wise_vec_func_simd(kind, "Div", "/", dim),
+        create_component_wise_vec_func(kind, "Rem", "%", dim),
+        create_scalar_vec_func_simd(kind, "Mul", "*", dim),
+        create_scalar_vec_func_simd(kind, "Div", "/", dim),
+        create_scalar_vec_func(kind, "Rem", "%", dim),
+        create_typed_op_assign_func(kind, "AddAssign", "add_assign", "+", "Self", dim),
+        create_typed_op_assign_func(kind, "SubAssign", "sub_assign", "-", "Self", dim),
+        create_op_assign_funcs(kind, "MulAssign", "mul_assign", "*", dim),
+        create_op_assign_funcs(kind, "DivAssign", "div_assign", "/", dim),
+        create_op_assign_funcs(kind, "RemAssign", "rem_assign", "%", dim),
+        create_vec_index_func(kind, dim),
+        create_vec_index_mut_func(kind, dim),
+        create_neg_func(kind, dim),
+        create_conversion_impls(&kind.vec(dim), kind.scalar, &VEC_FIELDS[..dim]),
+        create_precision_conversion_impl(kind, dim),
+        create_float_math_funcs(kind, dim),
+        create_simd_math_funcs(kind, dim),
+        create_ref_ops(kind, dim, &ARITHMETIC_OPS),
+        create_scalar_lhs_ops(kind, dim),
+        create_sum_product_impls(&kind.vec(dim), "one"),
+    ] {
+        result.write_str(s.as_str()).unwrap();
+    }
+
+    result
+}
+
+// Vector3A goes to its own file, included next to the struct so the generated code can set
+// the private padding lane.
+fn create_aligned_vec_math() -> String {
+    let mut result = String::new();
+
+    result
+        .write_str("/*** This code was generated automatically ***/\n\n")
+        .unwrap();
+    result.write_str(create_float_vec(F32A_KIND, 3).as_str()).unwrap();
+    result.write_str(create_compare_funcs(F32A_KIND, 3).as_str()).unwrap();
+
+    result
+}
+
+fn create_bool_vec(dim: usize) -> String {
+    let ty = format!("BVector{dim}");
+    let fields = VEC_FIELDS.iter().take(dim);
+
+    let field_defs = fields.clone().map(|f| format!("pub {f}: bool,")).join("\n");
+    let params_list = fields.clone().map(|f| format!("{f}: bool")).join(",");
+    let args_list = fields.clone().join(",");
+    let splat_args = (0..dim).map(|_| "value").join(",");
+    let any = fields.clone().map(|f| format!("self.{f}")).join(" || ");
+    let all = fields.clone().map(|f| format!("self.{f}")).join(" && ");
+    let bitmask = fields
+        .clone()
+        .enumerate()
+        .map(|(i, f)| match i {
+            0 => format!("self.{f} as u32"),
+            _ => format!("(self.{f} as u32) << {i}"),
+        })
+        .join(" | ");
+    let not_args = fields.map(|f| format!("!self.{f}")).join(",");
+    let conversions = create_conversion_impls(&ty, "bool", &VEC_FIELDS[..dim]);
+
+    let bit_ops = [("BitAnd", "&"), ("BitOr", "|"), ("BitXor", "^")]
+        .iter()
+        .map(|(op_trait, op_token)| {
+            let func_name = op_trait.to_lowercase();
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("self.{f} {op_token} rhs.{f}"))
+                .join(",");
+
+            format!(
+                "
+                impl core::ops::{op_trait}<{ty}> for {ty} {{
+                    type Output = {ty};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: {ty}) -> {ty} {{ {ty}::new({args}) }}
+                }}
+                "
+            )
+        })
+        .join("");
+
+    format!(
+        "
+        #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug, Default)]
+        #[repr(C)]
+        pub struct {ty} {{
+            {field_defs}
+        }}
+
+        impl {ty} {{
+            #[inline]
+            pub const fn new({params_list}) -> {ty} {{ {ty} {{ {args_list} }} }}
+
+            #[inline]
+            pub const fn splat(value: bool) -> {ty} {{ {ty}::new({splat_args}) }}
+
+            pub const FALSE: {ty} = {ty}::splat(false);
+            pub const TRUE: {ty} = {ty}::splat(true);
+
+            #[inline]
+            pub fn any(self) -> bool {{ {any} }}
+
+            #[inline]
+            pub fn all(self) -> bool {{ {all} }}
+
+            #[inline]
+            pub fn bitmask(self) -> u32 {{ {bitmask} }}
+        }}
+
+        {bit_ops}
+
+        impl core::ops::Not for {ty} {{
+            type Output = {ty};
+
+            #[inline]
+            fn not(self) -> {ty} {{ {ty}::new({not_args}) }}
+        }}
+
+        {conversions}
+        "
+    )
+}
+
+fn create_compare_funcs(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let mask_ty = format!("BVector{dim}");
+    let simd_prefix = get_simd_prefix(kind, dim);
+
+    let compares = [
+        ("cmpeq", "=="),
+        ("cmpne", "!="),
+        ("cmplt", "<"),
+        ("cmple", "<="),
+        ("cmpgt", ">"),
+        ("cmpge", ">="),
+    ]
+    .iter()
+    .map(|(func_name, op_token)| {
+        let args = VEC_FIELDS
+            .iter()
+            .take(dim)
+            .map(|f| format!("self.{f} {op_token} rhs.{f}"))
+            .join(",");
+
+        format!(
+            "#[inline] pub fn {func_name}(self, rhs: {ty}) -> {mask_ty} {{ {mask_ty}::new({args}) }}\n"
+        )
+    })
+    .join("");
+
+    let select_args = VEC_FIELDS
+        .iter()
+        .take(dim)
+        .map(|f| format!("if mask.{f} {{ a.{f} }} else {{ b.{f} }}"))
+        .join(",");
+
+    format!(
+        "
+        {simd_prefix}
+        impl {ty} {{
+            {compares}
+
+            #[inline]
+            pub fn select(mask: {mask_ty}, a: {ty}, b: {ty}) -> {ty} {{
+                {ty}::new({select_args})
+            }}
+        }}
+        "
+    )
+}
+
+fn create_int_vec(kind: VecKind, dim: usize) -> String {
+    let mut result = create_struct_def(kind, dim);
+
+    result
+        .write_fmt(format_args!("impl {} {{\n\n", kind.vec(dim)))
+        .unwrap();
+
+    for s in [
+        create_new_func(kind, dim),
+        create_all_func(kind, dim),
+        create_zero_func(kind, dim),
+        create_one_func(kind, dim),
+        create_vec_consts(kind, dim),
+        create_dot_func(kind, dim),
+        create_ptr_funcs(kind, dim),
+        create_int_min_max_funcs(kind, dim),
+        create_horizontal_min_max_funcs(kind, dim),
+        create_int_signed_funcs(kind, dim),
+        create_int_arithmetic_funcs(kind, "wrapping", dim),
+        create_int_arithmetic_funcs(kind, "saturating", dim),
+        create_int_checked_funcs(kind, dim),
+        create_int_cast_funcs(kind, dim),
+    ] {
+        result.write_str(s.as_str()).unwrap();
+    }
+
+    result.write_str("\n}\n").unwrap();
+
+    for s in [
+        create_component_wise_vec_func(kind, "Add", "+", dim),
+        create_component_wise_vec_func(kind, "Sub", "-", dim),
+        create_component_wise_vec_func(kind, "Mul", "*", dim),
+        create_component_wise_vec_func(kind, "Div", "/", dim),
+        create_component_wise_vec_func(kind, "Rem", "%", dim),
+        create_component_wise_vec_func(kind, "BitAnd", "&", dim),
+        create_component_wise_vec_func(kind, "BitOr", "|", dim),
+        create_component_wise_vec_func(kind, "BitXor", "^", dim),
+        create_scalar_vec_func(kind, "Mul", "*", dim),
+        create_scalar_vec_func(kind, "Div", "/", dim),
+        create_scalar_vec_func(kind, "Rem", "%", dim),
+        create_scalar_vec_func(kind, "BitAnd", "&", dim),
+        create_scalar_vec_func(kind, "BitOr", "|", dim),
+        create_scalar_vec_func(kind, "BitXor", "^", dim),
+        create_shift_funcs(kind, "Shl", "<<", dim),
+        create_shift_funcs(kind, "Shr", ">>", dim),
+        create_typed_op_assign_func(kind, "AddAssign", "add_assign", "+", "Self", dim),
+        create_typed_op_assign_func(kind, "SubAssign", "sub_assign", "-", "Self", dim),
+        create_op_assign_funcs(kind, "MulAssign", "mul_assign", "*", dim),
+        create_op_assign_funcs(kind, "DivAssign", "div_assign", "/", dim),
+        create_op_assign_funcs(kind, "RemAssign", "rem_assign", "%", dim),
+        create_op_assign_funcs(kind, "BitAndAssign", "bitand_assign", "&", dim),
+        create_op_assign_funcs(kind, "BitOrAssign", "bitor_assign", "|", dim),
+        create_op_assign_funcs(kind, "BitXorAssign", "bitxor_assign", "^", dim),
+        create_typed_op_assign_func(kind, "ShlAssign", "shl_assign", "<<", "u32", dim),
+        create_typed_op_assign_func(kind, "ShrAssign", "shr_assign", ">>", "u32", dim),
+        create_not_func(kind, dim),
+        create_vec_index_func(kind, dim),
+        create_vec_index_mut_func(kind, dim),
+        create_conversion_impls(&kind.vec(dim), kind.scalar, &VEC_FIELDS[..dim]),
+        create_int_float_conversion_impl(kind, dim),
+        create_ref_ops(kind, dim, &ARITHMETIC_OPS),
+        create_ref_ops(kind, dim, &BITWISE_OPS),
+        create_scalar_lhs_ops(kind, dim),
+        create_sum_product_impls(&kind.vec(dim), "one"),
+    ] {
+        result.write_str(s.as_str()).unwrap();
+    }
+
+    if kind.is_signed() {
+        result.write_str(create_neg_func(kind, dim).as_str()).unwrap();
     }
 
     result
 }
 
+fn create_struct_def(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+    let derives = if kind.is_float() {
+        "PartialEq, Copy, Clone, Debug, Default"
+    } else {
+        "PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Debug, Default"
+    };
+    let field_defs = VEC_FIELDS
+        .iter()
+        .take(dim)
+        .map(|f| format!("pub {f}: {scalar},"))
+        .join("\n");
+
+    format!(
+        "
+        #[derive({derives})]
+        #[repr(C)]
+        pub struct {ty} {{
+            {field_defs}
+        }}
+        "
+    )
+}
+
 fn create_expanded_math_func(
+    kind: VecKind,
     func_name: &str,
     vec_dimension: usize,
     additional_args: &[FuncArg],
 ) -> String {
-    let vec_type = format!("Vector{vec_dimension}");
+    let vec_type = kind.vec(vec_dimension);
 
-    let std_defs = format!("p0: Vector{vec_dimension}, p1: Vector{vec_dimension}");
+    let std_defs = format!("p0: {vec_type}, p1: {vec_type}");
     let func_call_args = VEC_FIELDS
         .iter()
         .take(vec_dimension)
@@ -189,90 +674,221 @@ fn create_expanded_math_func(
     let arg_defs = [std_defs, add_arg_defs].join(", ");
 
     format!(
-        "#[inline] pub fn {func_name}({arg_defs}) -> {vec_type} 
+        "#[inline] pub fn {func_name}({arg_defs}) -> {vec_type}
         {{ {vec_type}::new({func_call_args}) }}\n"
     )
 }
 
-fn create_new_func(dim: usize) -> String {
+fn create_new_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
     let params_list = VEC_FIELDS
         .iter()
         .take(dim)
-        .map(|f| format!("{f}: f32"))
+        .map(|f| format!("{f}: {scalar}"))
         .join(",");
-    let args_list = VEC_FIELDS.iter().take(dim).join(",");
+    let mut args_list = VEC_FIELDS.iter().take(dim).join(",");
+    if kind.aligned {
+        args_list.push_str(", w: 0.");
+    }
 
-    format!("pub fn new({params_list}) -> Vector{dim} {{ Vector{dim} {{ {args_list} }} }}")
+    format!("#[inline] pub const fn new({params_list}) -> {ty} {{ {ty} {{ {args_list} }} }}\n")
 }
 
-fn create_all_func(dim: usize) -> String {
+fn create_all_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
     let args_list = (0..dim).map(|_| "value").join(",");
 
     format!(
-        "#[inline] pub fn all(value: f32) -> Vector{dim} 
-        {{ Vector{dim}::new({args_list}) }}\n",
+        "#[inline] pub const fn all(value: {scalar}) -> {ty}
+        {{ {ty}::new({args_list}) }}\n",
     )
 }
 
-fn create_zero_func(dim: usize) -> String {
-    format!("#[inline] pub fn zero() -> Vector{dim} {{ Vector{dim}::all(0f32) }}\n")
+fn create_zero_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+
+    format!("#[inline] pub const fn zero() -> {ty} {{ {ty}::all(0{scalar}) }}\n")
+}
+
+fn create_one_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+
+    format!("#[inline] pub const fn one() -> {ty} {{ {ty}::all(1{scalar}) }}\n")
 }
 
-fn create_one_func(dim: usize) -> String {
-    format!("#[inline] pub fn one() -> Vector{dim} {{ Vector{dim}::all(1f32) }}\n")
+fn create_vec_consts(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+    let mut result = format!(
+        "
+        pub const ZERO: {ty} = {ty}::all(0{scalar});
+        pub const ONE: {ty} = {ty}::all(1{scalar});
+        pub const MIN: {ty} = {ty}::all({scalar}::MIN);
+        pub const MAX: {ty} = {ty}::all({scalar}::MAX);
+        "
+    );
+
+    if kind.is_signed() {
+        result
+            .write_fmt(format_args!("pub const NEG_ONE: {ty} = {ty}::all(-1{scalar});\n"))
+            .unwrap();
+    }
+
+    if kind.is_float() {
+        result
+            .write_fmt(format_args!(
+                "
+                pub const NAN: {ty} = {ty}::all({scalar}::NAN);
+                pub const INFINITY: {ty} = {ty}::all({scalar}::INFINITY);
+                pub const NEG_INFINITY: {ty} = {ty}::all({scalar}::NEG_INFINITY);
+                "
+            ))
+            .unwrap();
+    }
+
+    for (axis, field) in VEC_FIELDS.iter().take(dim).enumerate() {
+        let unit = |one: &str| {
+            (0..dim)
+                .map(|i| if i == axis { format!("{one}{scalar}") } else { format!("0{scalar}") })
+                .join(",")
+        };
+        let name = field.to_uppercase();
+
+        result
+            .write_fmt(format_args!("pub const {name}: {ty} = {ty}::new({});\n", unit("1")))
+            .unwrap();
+
+        if kind.is_signed() {
+            result
+                .write_fmt(format_args!("pub const NEG_{name}: {ty} = {ty}::new({});\n", unit("-1")))
+                .unwrap();
+        }
+    }
+
+    result
 }
 
-fn create_length_funcs(dim: usize) -> String {
+fn create_dot_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
     let formula = VEC_FIELDS
         .iter()
         .take(dim)
-        .map(|f| format!("self.{f} * self.{f}"))
-        .join("+");
+        .map(|f| format!("l.{f} * r.{f}"))
+        .join(" + ");
+
+    if kind.aligned {
+        return format!(
+            "#[inline] pub fn dot(l: {ty}, r: {ty}) -> {scalar} {{
+                let p = l.to_vec4_as_direction() * r.to_vec4_as_direction();
+                p.x + p.y + p.z
+            }}\n"
+        );
+    }
+
+    format!("#[inline] pub fn dot(l: {ty}, r: {ty}) -> {scalar} {{ {formula} }}\n")
+}
+
+fn create_cross_func(kind: VecKind, dim: usize) -> String {
+    if dim != 3 {
+        return String::new();
+    }
+
+    let ty = kind.vec(dim);
+
+    if kind.aligned {
+        return format!(
+            "
+            #[inline]
+            pub fn cross(l: {ty}, r: {ty}) -> {ty} {{
+                let l_yzx = Vector4::new(l.y, l.z, l.x, 0.);
+                let l_zxy = Vector4::new(l.z, l.x, l.y, 0.);
+                let r_yzx = Vector4::new(r.y, r.z, r.x, 0.);
+                let r_zxy = Vector4::new(r.z, r.x, r.y, 0.);
+
+                {ty}::from(l_yzx * r_zxy - l_zxy * r_yzx)
+            }}
+            "
+        );
+    }
+
+    format!(
+        "
+        #[inline]
+        pub fn cross(l: {ty}, r: {ty}) -> {ty} {{
+            {ty}::new(l.y * r.z - l.z * r.y, l.z * r.x - l.x * r.z, l.x * r.y - l.y * r.x)
+        }}
+        "
+    )
+}
+
+fn create_length_funcs(kind: VecKind, dim: usize) -> String {
+    let scalar = kind.scalar;
+    let formula = if kind.aligned {
+        format!("{}::dot(self, self)", kind.vec(dim))
+    } else {
+        VEC_FIELDS
+            .iter()
+            .take(dim)
+            .map(|f| format!("self.{f} * self.{f}"))
+            .join("+")
+    };
 
     format!(
         "
-        #[inline] pub fn length_sqr(self) -> f32 {{ {formula} }}
-        #[inline] pub fn length(self) -> f32 {{ self.length_sqr().sqrt() }}
+        #[inline] pub fn length_sqr(self) -> {scalar} {{ {formula} }}
+        #[inline] pub fn length(self) -> {scalar} {{ self.length_sqr().sqrt() }}
     "
     )
 }
 
-fn create_approx_func(vec_dimension: usize) -> String {
+fn create_approx_func(kind: VecKind, vec_dimension: usize) -> String {
     let fields = VEC_FIELDS.iter().take(vec_dimension);
-    let vec_type = format!("Vector{vec_dimension}");
+    let vec_type = kind.vec(vec_dimension);
+    let approx_fn = kind.approx_fn();
 
     let approx_calls = fields
         .into_iter()
-        .map(|f| format!("approx(x.{f}, y.{f})"))
+        .map(|f| format!("{approx_fn}(x.{f}, y.{f})"))
         .join(" && ");
 
     format!(
-        "#[inline] pub fn approx(x: {vec_type}, y: {vec_type}) -> bool 
+        "#[inline] pub fn approx(x: {vec_type}, y: {vec_type}) -> bool
         {{ {approx_calls} }}\n"
     )
 }
 
-fn create_normalized_func(dim: usize) -> String {
-    format!("#[inline] pub fn normalized(self) -> Vector{dim} {{ self / self.length() }}")
+fn create_normalized_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+
+    format!("#[inline] pub fn normalized(self) -> {ty} {{ self / self.length() }}")
 }
 
-fn create_ptr_funcs(dim: usize) -> String {
+fn create_ptr_funcs(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+
     format!(
         "
             #[inline]
-            pub fn as_ptr(&self) -> *const f32 {{
-                self as *const Vector{dim} as *const f32
+            pub fn as_ptr(&self) -> *const {scalar} {{
+                self as *const {ty} as *const {scalar}
             }}
             #[inline]
-            pub fn as_mut_ptr(&mut self) -> *mut f32 {{
-                self as *mut Vector{dim} as *mut f32
+            pub fn as_mut_ptr(&mut self) -> *mut {scalar} {{
+                self as *mut {ty} as *mut {scalar}
             }}
         "
     )
 }
 
-fn create_clamp_funcs(dim: usize) -> String {
-    let ty = format!("Vector{dim}");
+fn create_clamp_funcs(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
     let construction = VEC_FIELDS.iter().take(dim)
         .map(|f| format!("self.{f}.clamp(min.{f}, max.{f})"))
         .join(",");
@@ -284,7 +900,7 @@ fn create_clamp_funcs(dim: usize) -> String {
         }}
 
         #[inline]
-        pub fn clamp_length(self, min: f32, max: f32) -> {ty} {{
+        pub fn clamp_length(self, min: {scalar}, max: {scalar}) -> {ty} {{
             let sqr_min = min * min;
             let sqr_max = max * max;
 
@@ -295,106 +911,652 @@ fn create_clamp_funcs(dim: usize) -> String {
             }} else if len_sqr > sqr_max {{
                 self.normalized() * sqr_max.sqrt()
             }} else {{
-                return self;
+                self
             }}
         }}
     ")
 }
 
-fn create_component_wise_vec_func_simd(
-    operator_trait: &str,
-    operator_token: &str,
-    dim: usize,
-) -> String {
-    let simd_prefix = get_simd_prefix(dim);
-    let func = create_component_wise_vec_func(operator_trait, operator_token, dim);
+fn create_packing_funcs(kind: VecKind, dim: usize) -> String {
+    if !kind.is_f32() {
+        return String::new();
+    }
 
-    format!(
-        "
-        {simd_prefix}
-        {func}
-        "
-    )
-}
+    let ty = kind.vec(dim);
+    let formats = [
+        ("unorm8", "u8"),
+        ("snorm8", "i8"),
+        ("unorm16", "u16"),
+        ("snorm16", "i16"),
+    ];
 
-fn create_component_wise_vec_func(
-    operator_trait: &str,
-    operator_token: &str,
-    dim: usize,
-) -> String {
-    let func_name = operator_trait.to_lowercase();
-    let new_vec_result_args = VEC_FIELDS
+    formats
         .iter()
-        .take(dim)
-        .map(|f| format!("self.{f} {operator_token} rhs.{f}"))
-        .join(",");
+        .map(|(format, int_type)| {
+            let packed = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("crate::packing::pack_{format}(self.{f})"))
+                .join(",");
+            let unpacked = (0..dim)
+                .map(|i| format!("crate::packing::unpack_{format}(v[{i}])"))
+                .join(",");
 
-    format!(
-        "
-            impl std::ops::{operator_trait}<Vector{dim}> for Vector{dim} {{
-                type Output = Vector{dim};
+            format!(
+                "
+                #[inline]
+                pub fn to_{format}(self) -> [{int_type}; {dim}] {{ [{packed}] }}
 
                 #[inline]
-                fn {func_name}(self, rhs: Vector{dim}) -> Vector{dim} 
-                {{ Vector{dim}::new({new_vec_result_args}) }}
-            }}
-        "
-    )
+                pub fn from_{format}(v: [{int_type}; {dim}]) -> {ty} {{
+                    {ty}::new({unpacked})
+                }}
+                "
+            )
+        })
+        .join("")
 }
 
-fn create_scalar_vec_func_simd(operator_trait: &str, operator_token: &str, dim: usize) -> String {
-    let simd_prefix = get_simd_prefix(dim);
-    let func = create_scalar_vec_func(operator_trait, operator_token, dim);
+fn create_precision_funcs(kind: VecKind, dim: usize) -> String {
+    let (target, target_scalar) = if kind.is_f32() {
+        (F64_KIND.vec(dim), F64_KIND.scalar)
+    } else {
+        (F32_KIND.vec(dim), F32_KIND.scalar)
+    };
+
+    let args = VEC_FIELDS
+        .iter()
+        .take(dim)
+        .map(|f| format!("self.{f} as {target_scalar}"))
+        .join(",");
 
     format!(
         "
-        {simd_prefix}
-        {func}
+        #[inline]
+        pub fn as_{target_scalar}(self) -> {target} {{ {target}::new({args}) }}
         "
     )
 }
 
-fn create_scalar_vec_func(operator_trait: &str, operator_token: &str, dim: usize) -> String {
-    let func_name = operator_trait.to_lowercase();
-    let new_vec_result_args = VEC_FIELDS
+fn create_rounding_cast_funcs(kind: VecKind, dim: usize) -> String {
+    let scalar = kind.scalar;
+
+    INT_KINDS
         .iter()
-        .take(dim)
-        .map(|f| format!("self.{f} {operator_token} rhs"))
-        .join(",");
+        .map(|target| {
+            let target_ty = target.vec(dim);
+            let target_scalar = target.scalar;
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("mode.round_{scalar}(self.{f}) as {target_scalar}"))
+                .join(",");
 
-    format!(
+            format!(
+                "
+                #[inline]
+                pub fn as_{target_scalar}(self, mode: RoundingMode) -> {target_ty} {{
+                    {target_ty}::new({args})
+                }}
+                "
+            )
+        })
+        .join("")
+}
+
+fn create_precision_conversion_impl(kind: VecKind, dim: usize) -> String {
+    if kind.is_f32() {
+        return String::new();
+    }
+
+    let ty = kind.vec(dim);
+    let source = F32_KIND.vec(dim);
+
+    format!(
+        "
+            impl From<{source}> for {ty} {{
+                #[inline]
+                fn from(v: {source}) -> {ty} {{ v.as_f64() }}
+            }}
+        "
+    )
+}
+
+fn create_int_min_max_funcs(kind: VecKind, dim: usize) -> String {
+    create_binary_map_funcs(kind, dim, &["min", "max"])
+}
+
+fn create_horizontal_min_max_funcs(kind: VecKind, dim: usize) -> String {
+    let scalar = kind.scalar;
+
+    ["min", "max"]
+        .iter()
+        .map(|func| {
+            let formula = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .skip(1)
+                .fold("self.x".to_string(), |acc, f| format!("{acc}.{func}(self.{f})"));
+
+            format!("#[inline] pub fn {func}_element(self) -> {scalar} {{ {formula} }}\n")
+        })
+        .join("")
+}
+
+fn create_int_signed_funcs(kind: VecKind, dim: usize) -> String {
+    if !kind.is_signed() {
+        return String::new();
+    }
+
+    create_unary_map_funcs(kind, dim, &["abs", "signum"])
+}
+
+fn create_unary_map_funcs(kind: VecKind, dim: usize, funcs: &[&str]) -> String {
+    let ty = kind.vec(dim);
+
+    funcs
+        .iter()
+        .map(|func| {
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("self.{f}.{func}()"))
+                .join(",");
+
+            format!("#[inline] pub fn {func}(self) -> {ty} {{ {ty}::new({args}) }}\n")
+        })
+        .join("")
+}
+
+fn create_binary_map_funcs(kind: VecKind, dim: usize, funcs: &[&str]) -> String {
+    let ty = kind.vec(dim);
+
+    funcs
+        .iter()
+        .map(|func| {
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("self.{f}.{func}(rhs.{f})"))
+                .join(",");
+
+            format!("#[inline] pub fn {func}(self, rhs: {ty}) -> {ty} {{ {ty}::new({args}) }}\n")
+        })
+        .join("")
+}
+
+fn create_float_math_funcs(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let mask_ty = format!("BVector{dim}");
+    let scalar = kind.scalar;
+    let unary = create_unary_map_funcs(
+        kind,
+        dim,
+        &["signum", "fract", "recip", "exp", "ln", "sin", "cos"],
+    );
+    let binary = create_binary_map_funcs(kind, dim, &["copysign"]);
+    let fields = VEC_FIELDS.iter().take(dim);
+    let powf_args = fields.clone().map(|f| format!("self.{f}.powf(n)")).join(",");
+    let is_nan_args = fields.clone().map(|f| format!("self.{f}.is_nan()")).join(",");
+    let is_finite_args = fields.map(|f| format!("self.{f}.is_finite()")).join(",");
+
+    format!(
+        "
+        impl {ty} {{
+            {unary}
+            {binary}
+
+            #[inline]
+            pub fn powf(self, n: {scalar}) -> {ty} {{ {ty}::new({powf_args}) }}
+
+            #[inline]
+            pub fn is_nan(self) -> {mask_ty} {{ {mask_ty}::new({is_nan_args}) }}
+
+            #[inline]
+            pub fn is_finite(self) -> {mask_ty} {{ {mask_ty}::new({is_finite_args}) }}
+        }}
+        "
+    )
+}
+
+// Functions that have a hand-written SIMD counterpart for Vector4 under `enable_simd`.
+fn create_simd_math_funcs(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let simd_prefix = get_simd_prefix(kind, dim);
+    let unary_funcs = ["abs", "floor", "ceil", "round", "trunc", "sqrt"];
+    let binary_funcs = ["min", "max"];
+    let horizontal = create_horizontal_min_max_funcs(kind, dim);
+
+    if kind.aligned {
+        let as_vec4 = |v: &str| format!("{v}.to_vec4_as_direction()");
+        let unary = unary_funcs
+            .iter()
+            .map(|func| {
+                let body = aligned_vec4_op(&ty, &format!("{}.{func}()", as_vec4("self")));
+                format!("#[inline] pub fn {func}(self) -> {ty} {{ {body} }}\n")
+            })
+            .join("");
+        let binary = binary_funcs
+            .iter()
+            .map(|func| {
+                let body = aligned_vec4_op(&ty, &format!("{}.{func}({})", as_vec4("self"), as_vec4("rhs")));
+                format!("#[inline] pub fn {func}(self, rhs: {ty}) -> {ty} {{ {body} }}\n")
+            })
+            .join("");
+        let mul_add = aligned_vec4_op(
+            &ty,
+            &format!("{}.mul_add({}, {})", as_vec4("self"), as_vec4("a"), as_vec4("b")),
+        );
+
+        return format!(
+            "
+            impl {ty} {{
+                {unary}
+                {binary}
+                {horizontal}
+
+                #[inline]
+                pub fn mul_add(self, a: {ty}, b: {ty}) -> {ty} {{ {mul_add} }}
+            }}
+            "
+        );
+    }
+
+    let unary = create_unary_map_funcs(kind, dim, &unary_funcs);
+    let binary = create_binary_map_funcs(kind, dim, &binary_funcs);
+    let mul_add_args = VEC_FIELDS
+        .iter()
+        .take(dim)
+        .map(|f| format!("self.{f}.mul_add(a.{f}, b.{f})"))
+        .join(",");
+
+    format!(
+        "
+        {simd_prefix}
+        impl {ty} {{
+            {unary}
+            {binary}
+            {horizontal}
+
+            #[inline]
+            pub fn mul_add(self, a: {ty}, b: {ty}) -> {ty} {{ {ty}::new({mul_add_args}) }}
+        }}
+        "
+    )
+}
+
+fn create_int_arithmetic_funcs(kind: VecKind, prefix: &str, dim: usize) -> String {
+    let ty = kind.vec(dim);
+
+    ["add", "sub", "mul"]
+        .iter()
+        .map(|op| {
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("self.{f}.{prefix}_{op}(rhs.{f})"))
+                .join(",");
+
+            format!(
+                "#[inline] pub fn {prefix}_{op}(self, rhs: {ty}) -> {ty} {{ {ty}::new({args}) }}\n"
+            )
+        })
+        .join("")
+}
+
+fn create_int_checked_funcs(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+
+    ["add", "sub", "mul", "div"]
+        .iter()
+        .map(|op| {
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("self.{f}.checked_{op}(rhs.{f})?"))
+                .join(",");
+
+            format!(
+                "#[inline] pub fn checked_{op}(self, rhs: {ty}) -> Option<{ty}> {{
+                    Some({ty}::new({args}))
+                }}\n"
+            )
+        })
+        .join("")
+}
+
+fn create_int_cast_funcs(kind: VecKind, dim: usize) -> String {
+    ALL_KINDS
+        .iter()
+        .filter(|target| target.scalar != kind.scalar)
+        .map(|target| {
+            let target_ty = target.vec(dim);
+            let target_scalar = target.scalar;
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("self.{f} as {target_scalar}"))
+                .join(",");
+
+            format!(
+                "#[inline] pub fn as_{target_scalar}(self) -> {target_ty} {{ {target_ty}::new({args}) }}\n"
+            )
+        })
+        .join("")
+}
+
+fn create_int_float_conversion_impl(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let target = F64_KIND.vec(dim);
+
+    format!(
+        "
+            impl From<{ty}> for {target} {{
+                #[inline]
+                fn from(v: {ty}) -> {target} {{ v.as_f64() }}
+            }}
+        "
+    )
+}
+
+fn create_shift_funcs(kind: VecKind, operator_trait: &str, operator_token: &str, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let shift_ty = U32_KIND.vec(dim);
+    let func_name = operator_trait.to_lowercase();
+    let scalar_args = VEC_FIELDS
+        .iter()
+        .take(dim)
+        .map(|f| format!("self.{f} {operator_token} rhs"))
+        .join(",");
+    let vec_args = VEC_FIELDS
+        .iter()
+        .take(dim)
+        .map(|f| format!("self.{f} {operator_token} rhs.{f}"))
+        .join(",");
+
+    format!(
+        "
+            impl core::ops::{operator_trait}<u32> for {ty} {{
+                type Output = {ty};
+
+                #[inline]
+                fn {func_name}(self, rhs: u32) -> {ty} {{ {ty}::new({scalar_args}) }}
+            }}
+
+            impl core::ops::{operator_trait}<{shift_ty}> for {ty} {{
+                type Output = {ty};
+
+                #[inline]
+                fn {func_name}(self, rhs: {shift_ty}) -> {ty} {{ {ty}::new({vec_args}) }}
+            }}
+        "
+    )
+}
+
+fn create_not_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let new_args = VEC_FIELDS
+        .iter()
+        .take(dim)
+        .map(|f| format!("!self.{f}"))
+        .join(",");
+
+    format!(
+        "
+            impl core::ops::Not for {ty} {{
+                type Output = {ty};
+
+                #[inline]
+                fn not(self) -> Self::Output {{
+                    {ty}::new({new_args})
+                }}
+            }}
+        "
+    )
+}
+
+// Forwards the by-reference forms of a binary operator to the by-value impl.
+fn create_ref_op_impls(operator_trait: &str, operator_token: &str, ty: &str, rhs: &str) -> String {
+    let func_name = operator_trait.to_lowercase();
+
+    format!(
+        "
+            impl core::ops::{operator_trait}<&{rhs}> for &{ty} {{
+                type Output = {ty};
+
+                #[inline]
+                fn {func_name}(self, rhs: &{rhs}) -> {ty} {{ *self {operator_token} *rhs }}
+            }}
+
+            impl core::ops::{operator_trait}<&{rhs}> for {ty} {{
+                type Output = {ty};
+
+                #[inline]
+                fn {func_name}(self, rhs: &{rhs}) -> {ty} {{ self {operator_token} *rhs }}
+            }}
+
+            impl core::ops::{operator_trait}<{rhs}> for &{ty} {{
+                type Output = {ty};
+
+                #[inline]
+                fn {func_name}(self, rhs: {rhs}) -> {ty} {{ *self {operator_token} rhs }}
+            }}
+        "
+    )
+}
+
+fn create_ref_ops(kind: VecKind, dim: usize, ops: &[(&str, &str)]) -> String {
+    let ty = kind.vec(dim);
+
+    ops.iter()
+        .map(|(operator_trait, operator_token)| {
+            let vec_ops = create_ref_op_impls(operator_trait, operator_token, &ty, &ty);
+            let scalar_ops = if *operator_trait == "Add" || *operator_trait == "Sub" {
+                String::new()
+            } else {
+                create_ref_op_impls(operator_trait, operator_token, &ty, kind.scalar)
+            };
+
+            format!("{vec_ops}{scalar_ops}")
+        })
+        .join("")
+}
+
+fn create_scalar_lhs_ops(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+
+    [("Mul", "*"), ("Div", "/")]
+        .iter()
+        .map(|(operator_trait, operator_token)| {
+            let func_name = operator_trait.to_lowercase();
+            let args = VEC_FIELDS
+                .iter()
+                .take(dim)
+                .map(|f| format!("self {operator_token} rhs.{f}"))
+                .join(",");
+
+            format!(
+                "
+                impl core::ops::{operator_trait}<{ty}> for {scalar} {{
+                    type Output = {ty};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: {ty}) -> {ty} {{ {ty}::new({args}) }}
+                }}
+
+                impl core::ops::{operator_trait}<&{ty}> for {scalar} {{
+                    type Output = {ty};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: &{ty}) -> {ty} {{ self {operator_token} *rhs }}
+                }}
+                "
+            )
+        })
+        .join("")
+}
+
+fn create_sum_product_impls(ty: &str, product_identity: &str) -> String {
+    format!(
+        "
+            impl core::iter::Sum for {ty} {{
+                fn sum<I: Iterator<Item = {ty}>>(iter: I) -> {ty} {{
+                    iter.fold({ty}::zero(), |acc, v| acc + v)
+                }}
+            }}
+
+            impl<'a> core::iter::Sum<&'a {ty}> for {ty} {{
+                fn sum<I: Iterator<Item = &'a {ty}>>(iter: I) -> {ty} {{
+                    iter.fold({ty}::zero(), |acc, v| acc + *v)
+                }}
+            }}
+
+            impl core::iter::Product for {ty} {{
+                fn product<I: Iterator<Item = {ty}>>(iter: I) -> {ty} {{
+                    iter.fold({ty}::{product_identity}(), |acc, v| acc * v)
+                }}
+            }}
+
+            impl<'a> core::iter::Product<&'a {ty}> for {ty} {{
+                fn product<I: Iterator<Item = &'a {ty}>>(iter: I) -> {ty} {{
+                    iter.fold({ty}::{product_identity}(), |acc, v| acc * *v)
+                }}
+            }}
         "
-            impl std::ops::{operator_trait}<f32> for Vector{dim} {{
-                type Output = Vector{dim};
+    )
+}
+
+fn create_component_wise_vec_func_simd(
+    kind: VecKind,
+    operator_trait: &str,
+    operator_token: &str,
+    dim: usize,
+) -> String {
+    let simd_prefix = get_simd_prefix(kind, dim);
+    let func = create_component_wise_vec_func(kind, operator_trait, operator_token, dim);
+
+    format!("{simd_prefix}{func}")
+}
+
+fn create_component_wise_vec_func(
+    kind: VecKind,
+    operator_trait: &str,
+    operator_token: &str,
+    dim: usize,
+) -> String {
+    let ty = kind.vec(dim);
+    let func_name = operator_trait.to_lowercase();
+    let body = if kind.aligned {
+        aligned_vec4_op(&ty, &format!("self.to_vec4_as_direction() {operator_token} rhs.to_vec4_as_direction()"))
+    } else {
+        let new_vec_result_args = VEC_FIELDS
+            .iter()
+            .take(dim)
+            .map(|f| format!("self.{f} {operator_token} rhs.{f}"))
+            .join(",");
+
+        format!("{ty}::new({new_vec_result_args})")
+    };
+
+    format!(
+        "
+            impl core::ops::{operator_trait}<{ty}> for {ty} {{
+                type Output = {ty};
+
+                #[inline]
+                fn {func_name}(self, rhs: {ty}) -> {ty}
+                {{ {body} }}
+            }}
+        "
+    )
+}
+
+fn create_scalar_vec_func_simd(
+    kind: VecKind,
+    operator_trait: &str,
+    operator_token: &str,
+    dim: usize,
+) -> String {
+    let simd_prefix = get_simd_prefix(kind, dim);
+    let func = create_scalar_vec_func(kind, operator_trait, operator_token, dim);
+
+    format!("{simd_prefix}{func}")
+}
+
+fn create_scalar_vec_func(
+    kind: VecKind,
+    operator_trait: &str,
+    operator_token: &str,
+    dim: usize,
+) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+    let func_name = operator_trait.to_lowercase();
+    let body = if kind.aligned {
+        aligned_vec4_op(&ty, &format!("self.to_vec4_as_direction() {operator_token} rhs"))
+    } else {
+        let new_vec_result_args = VEC_FIELDS
+            .iter()
+            .take(dim)
+            .map(|f| format!("self.{f} {operator_token} rhs"))
+            .join(",");
+
+        format!("{ty}::new({new_vec_result_args})")
+    };
+
+    format!(
+        "
+            impl core::ops::{operator_trait}<{scalar}> for {ty} {{
+                type Output = {ty};
 
                 #[inline]
-                fn {func_name}(self, rhs: f32) -> Vector{dim} 
-                {{ Vector{dim}::new({new_vec_result_args}) }}
+                fn {func_name}(self, rhs: {scalar}) -> {ty}
+                {{ {body} }}
             }}
         "
     )
 }
 
-fn get_simd_prefix(dim: usize) -> String {
-    if dim == 4 {
-        "#[cfg(not(feature = \"enable_simd\"))]".to_string()
+// Runs `vec4_expr` on the Vector4 view of an aligned vector. Converting back drops w, so a
+// padding lane that turned into NaN (0 / 0, 0 * inf) never leaks into the result.
+fn aligned_vec4_op(ty: &str, vec4_expr: &str) -> String {
+    format!("{ty}::from({vec4_expr})")
+}
+
+fn get_simd_prefix(kind: VecKind, dim: usize) -> String {
+    if kind.is_f32() && dim == 4 {
+        "#[cfg(not(simd_backend))]".to_string()
     } else {
         String::new()
     }
 }
 
-fn create_vec_index_func(dim: usize) -> String {
+// Only the wasm simd128 backend has hand-written matrix products so far.
+fn get_matrix_simd_prefix(kind: VecKind) -> String {
+    if kind.is_f32() {
+        "#[cfg(not(all(simd_backend, target_arch = \"wasm32\", target_feature = \"simd128\")))]"
+            .to_string()
+    } else {
+        String::new()
+    }
+}
+
+fn create_vec_index_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+
     format!(
         "
-            impl std::ops::Index<usize> for Vector{dim} {{
-                type Output = f32;
+            impl core::ops::Index<usize> for {ty} {{
+                type Output = {scalar};
 
                 #[inline]
                 fn index(&self, index: usize) -> &Self::Output {{
-                    debug_assert!(index < {dim});
+                    assert!(index < {dim});
 
                     unsafe {{
-                        let ptr = self as *const Vector{dim} as *const f32;
+                        let ptr = self as *const {ty} as *const {scalar};
                         & *ptr.add(index)
                     }}
                 }}
@@ -403,16 +1565,19 @@ fn create_vec_index_func(dim: usize) -> String {
     )
 }
 
-fn create_vec_index_mut_func(dim: usize) -> String {
+fn create_vec_index_mut_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
+    let scalar = kind.scalar;
+
     format!(
         "
-            impl std::ops::IndexMut<usize> for Vector{dim} {{
+            impl core::ops::IndexMut<usize> for {ty} {{
                 #[inline]
                 fn index_mut(&mut self, index: usize) -> &mut Self::Output {{
                     assert!(index < {dim});
 
                     unsafe {{
-                        let ptr = self as *mut Vector{dim} as *mut f32;
+                        let ptr = self as *mut {ty} as *mut {scalar};
                         &mut *ptr.add(index)
                     }}
                 }}
@@ -421,21 +1586,27 @@ fn create_vec_index_mut_func(dim: usize) -> String {
     )
 }
 
-fn create_neg_func(dim: usize) -> String {
+fn create_neg_func(kind: VecKind, dim: usize) -> String {
+    let ty = kind.vec(dim);
     let new_args = VEC_FIELDS
         .iter()
         .take(dim)
         .map(|f| format!("-self.{f}"))
         .join(",");
+    let body = if kind.aligned {
+        aligned_vec4_op(&ty, "-self.to_vec4_as_direction()")
+    } else {
+        format!("{ty}::new({new_args})")
+    };
 
     format!(
         "
-            impl std::ops::Neg for Vector{dim} {{
-                type Output = Vector{dim};
+            impl core::ops::Neg for {ty} {{
+                type Output = {ty};
 
                 #[inline]
                 fn neg(self) -> Self::Output {{
-                    Vector{dim}::new({new_args})
+                    {body}
                 }}
             }}
         "
@@ -443,13 +1614,15 @@ fn create_neg_func(dim: usize) -> String {
 }
 
 fn create_op_assign_funcs(
+    kind: VecKind,
     op_trait: &str,
     func_name: &str,
     op_token: &str,
     dim: usize,
 ) -> String {
-    let vec_op_func = create_typed_op_assign_func(op_trait, func_name, op_token, "Self", dim);
-    let scalar_op_func = create_typed_op_assign_func(op_trait, func_name, op_token, "f32", dim);
+    let vec_op_func = create_typed_op_assign_func(kind, op_trait, func_name, op_token, "Self", dim);
+    let scalar_op_func =
+        create_typed_op_assign_func(kind, op_trait, func_name, op_token, kind.scalar, dim);
 
     format!(
         "
@@ -460,15 +1633,18 @@ fn create_op_assign_funcs(
 }
 
 fn create_typed_op_assign_func(
+    kind: VecKind,
     op_trait: &str,
     func_name: &str,
     op_token: &str,
     rhs_type: &str,
     dim: usize,
 ) -> String {
+    let ty = kind.vec(dim);
+
     format!(
         "
-        impl std::ops::{op_trait}<{rhs_type}> for Vector{dim} {{
+        impl core::ops::{op_trait}<{rhs_type}> for {ty} {{
             fn {func_name}(&mut self, rhs: {rhs_type}) {{
                 *self = * self {op_token} rhs
             }}
@@ -477,18 +1653,1414 @@ fn create_typed_op_assign_func(
     )
 }
 
-fn create_from_tuple_impl(dim: usize) -> String {
-    let tuple_type = format!("({})",(0..dim).map(|_| "f32").join(","));
-    let fields = VEC_FIELDS.iter().take(dim).join(",");
+// Array, tuple, slice and text conversions. `AsRef`/`AsMut` reinterpret the `#[repr(C)]`
+// fields, which are laid out like the array.
+fn create_conversion_impls(ty: &str, scalar: &str, field_names: &[&str]) -> String {
+    let dim = field_names.len();
+    let array_type = format!("[{scalar}; {dim}]");
+    let tuple_type = format!("({})", (0..dim).map(|_| scalar).join(","));
+    let fields = field_names.join(",");
+    let self_fields = field_names.iter().map(|f| format!("self.{f}")).join(",");
+    let v_fields = field_names.iter().map(|f| format!("v.{f}")).join(",");
+    let indexed = |name: &str| (0..dim).map(|i| format!("{name}[{i}]")).join(",");
+    let array_args = indexed("a");
+    let slice_args = indexed("slice");
 
     format!(
         "
-            impl From<{tuple_type}> for Vector{dim} {{
+            impl {ty} {{
+                #[inline]
+                pub const fn from_array(a: {array_type}) -> {ty} {{ {ty}::new({array_args}) }}
+
+                #[inline]
+                pub const fn to_array(self) -> {array_type} {{ [{self_fields}] }}
+
+                // Reads the first {dim} values, panics if the slice is shorter.
+                #[inline]
+                pub fn from_slice(slice: &[{scalar}]) -> {ty} {{ {ty}::new({slice_args}) }}
+
+                // Writes to the first {dim} values, panics if the slice is shorter.
                 #[inline]
-                fn from(({fields}): {tuple_type}) -> Vector{dim} {{
-                    Vector{dim}::new({fields})
+                pub fn write_to_slice(self, slice: &mut [{scalar}]) {{
+                    slice[..{dim}].copy_from_slice(&self.to_array());
                 }}
             }}
+
+            impl From<{tuple_type}> for {ty} {{
+                #[inline]
+                fn from(({fields}): {tuple_type}) -> {ty} {{
+                    {ty}::new({fields})
+                }}
+            }}
+
+            impl From<{ty}> for {tuple_type} {{
+                #[inline]
+                fn from(v: {ty}) -> {tuple_type} {{
+                    ({v_fields})
+                }}
+            }}
+
+            impl From<{array_type}> for {ty} {{
+                #[inline]
+                fn from(a: {array_type}) -> {ty} {{ {ty}::from_array(a) }}
+            }}
+
+            impl From<{ty}> for {array_type} {{
+                #[inline]
+                fn from(v: {ty}) -> {array_type} {{ v.to_array() }}
+            }}
+
+            impl AsRef<{array_type}> for {ty} {{
+                #[inline]
+                fn as_ref(&self) -> &{array_type} {{
+                    unsafe {{ &*(self as *const {ty} as *const {array_type}) }}
+                }}
+            }}
+
+            impl AsMut<{array_type}> for {ty} {{
+                #[inline]
+                fn as_mut(&mut self) -> &mut {array_type} {{
+                    unsafe {{ &mut *(self as *mut {ty} as *mut {array_type}) }}
+                }}
+            }}
+
+            impl core::fmt::Display for {ty} {{
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {{
+                    crate::format::fmt_components(f, &self.to_array())
+                }}
+            }}
+
+            impl core::str::FromStr for {ty} {{
+                type Err = crate::ParseMathError;
+
+                fn from_str(s: &str) -> Result<{ty}, crate::ParseMathError> {{
+                    crate::format::parse_components(s).map({ty}::from_array)
+                }}
+            }}
+        "
+    )
+}
+
+fn create_matrices() -> String {
+    let mut result = String::new();
+
+    result
+        .write_str("/*** This code was generated automatically ***/\n\n")
+        .unwrap();
+
+    for kind in FLOAT_KINDS {
+        result.write_str(create_matrix(kind).as_str()).unwrap();
+    }
+
+    result
+}
+
+fn create_matrix(kind: VecKind) -> String {
+    let mat = kind.mat();
+    let vec4 = kind.vec(4);
+    let vec3 = kind.vec(3);
+    let scalar = kind.scalar;
+    let approx = create_matrix_approx_func(kind);
+    let precision = create_matrix_precision_funcs(kind);
+    let precision_conversion = create_matrix_precision_conversion_impl(kind);
+    let ops = create_matrix_ops(kind);
+    let conversions = create_matrix_conversion_impls(kind);
+    let simd_prefix = get_matrix_simd_prefix(kind);
+
+    format!(
+        "
+        #[derive(PartialEq, Copy, Clone)]
+        #[repr(C)]
+        pub struct {mat} {{
+            c0: {vec4},
+            c1: {vec4},
+            c2: {vec4},
+            c3: {vec4},
+        }}
+
+        impl {mat} {{
+            #[inline]
+            pub const fn from_columns(c0: {vec4}, c1: {vec4}, c2: {vec4}, c3: {vec4}) -> {mat} {{
+                {mat} {{ c0, c1, c2, c3 }}
+            }}
+
+            #[inline]
+            pub const fn all(v: {scalar}) -> {mat} {{
+                let v = {vec4}::all(v);
+                {mat}::from_columns(v, v, v, v)
+            }}
+
+            #[inline]
+            pub const fn identity() -> {mat} {{
+                {mat}::from_columns(
+                    {vec4}::new(1., 0., 0., 0.),
+                    {vec4}::new(0., 1., 0., 0.),
+                    {vec4}::new(0., 0., 1., 0.),
+                    {vec4}::new(0., 0., 0., 1.),
+                )
+            }}
+
+            #[inline]
+            pub const fn scale(scale: {vec3}) -> {mat} {{
+                {mat} {{
+                    c0: {vec4}::new(scale.x, 0., 0., 0.),
+                    c1: {vec4}::new(0., scale.y, 0., 0.),
+                    c2: {vec4}::new(0., 0., scale.z, 0.),
+                    c3: {vec4}::new(0., 0., 0., 1.),
+                }}
+            }}
+
+            #[inline]
+            pub const fn translation(t: {vec3}) -> {mat} {{
+                {mat} {{
+                    c0: {vec4}::new(1., 0., 0., 0.),
+                    c1: {vec4}::new(0., 1., 0., 0.),
+                    c2: {vec4}::new(0., 0., 1., 0.),
+                    c3: {vec4}::new(t.x, t.y, t.z, 1.),
+                }}
+            }}
+
+            #[inline]
+            pub const fn zero() -> {mat} {{
+                {mat}::all(0.)
+            }}
+
+            pub const ZERO: {mat} = {mat}::zero();
+            pub const IDENTITY: {mat} = {mat}::identity();
+            pub const NAN: {mat} = {mat}::all({scalar}::NAN);
+            pub const INFINITY: {mat} = {mat}::all({scalar}::INFINITY);
+            pub const NEG_INFINITY: {mat} = {mat}::all({scalar}::NEG_INFINITY);
+
+            #[inline]
+            pub fn ortho(left: {scalar}, right: {scalar}, bottom: {scalar}, top: {scalar}) -> Self {{
+                let dx = 1. / (right - left);
+                let dy = 1. / (top - bottom);
+
+                {mat}::from_columns(
+                    {vec4}::new(2. * dx, 0., 0., 0.),
+                    {vec4}::new(0., 2. * dy, 0., 0.),
+                    {vec4}::new(0., 0., -1., 0.),
+                    {vec4}::new(-(right + left) * dx, -(top + bottom) * dy, 0., 1.),
+                )
+            }}
+
+            #[inline]
+            pub fn ortho_with_near_far(
+                left: {scalar},
+                right: {scalar},
+                bottom: {scalar},
+                top: {scalar},
+                near: {scalar},
+                far: {scalar},
+            ) -> Self {{
+                let dx = 1. / (right - left);
+                let dy = 1. / (top - bottom);
+                let dz = 1. / (far - near);
+
+                {mat}::from_columns(
+                    {vec4}::new(2. * dx, 0., 0., -(right + left) * dx),
+                    {vec4}::new(0., 2. * dy, 0., -(top + bottom) * dy),
+                    {vec4}::new(0., 0., -2. * dz, -(far + near) * dz),
+                    {vec4}::new(0., 0., 0., 1.),
+                )
+            }}
+
+            pub fn inverse(self) -> {mat} {{
+                let c0 = self.c0;
+                let c1 = self.c1;
+                let c2 = self.c2;
+                let c3 = self.c3;
+
+                let c0_0 = c0.x;
+                let c0_1 = c0.y;
+                let c0_2 = c0.z;
+                let c0_3 = c0.w;
+                let c1_0 = c1.x;
+                let c1_1 = c1.y;
+                let c1_2 = c1.z;
+                let c1_3 = c1.w;
+                let c2_0 = c2.x;
+                let c2_1 = c2.y;
+                let c2_2 = c2.z;
+                let c2_3 = c2.w;
+                let c3_0 = c3.x;
+                let c3_1 = c3.y;
+                let c3_2 = c3.z;
+                let c3_3 = c3.w;
+
+                let coef00 = c2_2 * c3_3 - c3_2 * c2_3;
+                let coef02 = c1_2 * c3_3 - c3_2 * c1_3;
+                let coef03 = c1_2 * c2_3 - c2_2 * c1_3;
+
+                let coef04 = c2_1 * c3_3 - c3_1 * c2_3;
+                let coef06 = c1_1 * c3_3 - c3_1 * c1_3;
+                let coef07 = c1_1 * c2_3 - c2_1 * c1_3;
+
+                let coef08 = c2_1 * c3_2 - c3_1 * c2_2;
+                let coef10 = c1_1 * c3_2 - c3_1 * c1_2;
+                let coef11 = c1_1 * c2_2 - c2_1 * c1_2;
+
+                let coef12 = c2_0 * c3_3 - c3_0 * c2_3;
+                let coef14 = c1_0 * c3_3 - c3_0 * c1_3;
+                let coef15 = c1_0 * c2_3 - c2_0 * c1_3;
+
+                let coef16 = c2_0 * c3_2 - c3_0 * c2_2;
+                let coef18 = c1_0 * c3_2 - c3_0 * c1_2;
+                let coef19 = c1_0 * c2_2 - c2_0 * c1_2;
+
+                let coef20 = c2_0 * c3_1 - c3_0 * c2_1;
+                let coef22 = c1_0 * c3_1 - c3_0 * c1_1;
+                let coef23 = c1_0 * c2_1 - c2_0 * c1_1;
+
+                let fac0 = {vec4}::new(coef00, coef00, coef02, coef03);
+                let fac1 = {vec4}::new(coef04, coef04, coef06, coef07);
+                let fac2 = {vec4}::new(coef08, coef08, coef10, coef11);
+                let fac3 = {vec4}::new(coef12, coef12, coef14, coef15);
+                let fac4 = {vec4}::new(coef16, coef16, coef18, coef19);
+                let fac5 = {vec4}::new(coef20, coef20, coef22, coef23);
+
+                let vec0 = {vec4}::new(c1_0, c0_0, c0_0, c0_0);
+                let vec1 = {vec4}::new(c1_1, c0_1, c0_1, c0_1);
+                let vec2 = {vec4}::new(c1_2, c0_2, c0_2, c0_2);
+                let vec3 = {vec4}::new(c1_3, c0_3, c0_3, c0_3);
+
+                let inv0 = vec1 * fac0 - vec2 * fac1 + vec3 * fac2;
+                let inv1 = vec0 * fac0 - vec2 * fac3 + vec3 * fac4;
+                let inv2 = vec0 * fac1 - vec1 * fac3 + vec3 * fac5;
+                let inv3 = vec0 * fac2 - vec1 * fac4 + vec2 * fac5;
+
+                let sign_a = {vec4}::new(1., -1., 1., -1.);
+                let sign_b = {vec4}::new(-1., 1., -1., 1.);
+                let inverse =
+                    {mat}::from_columns(inv0 * sign_a, inv1 * sign_b, inv2 * sign_a, inv3 * sign_b);
+
+                let row0 = {vec4}::new(inverse.c0.x, inverse.c1.x, inverse.c2.x, inverse.c3.x);
+
+                let dot0 = c0 * row0;
+                let dot1 = (dot0.x + dot0.y) + (dot0.z + dot0.w);
+
+                let one_over_determinant = 1. / dot1;
+
+                inverse * one_over_determinant
+            }}
+
+            {approx}
+            {precision}
+        }}
+
+        impl Index<usize> for {mat} {{
+            type Output = {vec4};
+
+            #[inline]
+            fn index(&self, index: usize) -> &{vec4} {{
+                assert!(index < 4);
+
+                unsafe {{
+                    let ptr = self as *const {mat} as *const {vec4};
+                    &*ptr.add(index)
+                }}
+            }}
+        }}
+
+        impl IndexMut<usize> for {mat} {{
+            #[inline]
+            fn index_mut(&mut self, index: usize) -> &mut Self::Output {{
+                assert!(index < 4);
+
+                unsafe {{
+                    let ptr = self as *mut {mat} as *mut {vec4};
+                    &mut *ptr.add(index)
+                }}
+            }}
+        }}
+
+        impl Mul<{scalar}> for {mat} {{
+            type Output = {mat};
+
+            #[inline]
+            fn mul(self, rhs: {scalar}) -> Self::Output {{
+                {mat}::from_columns(self[0] * rhs, self[1] * rhs, self[2] * rhs, self[3] * rhs)
+            }}
+        }}
+
+        {simd_prefix}
+        impl Mul<{mat}> for {mat} {{
+            type Output = {mat};
+
+            #[inline]
+            fn mul(self, rhs: {mat}) -> Self::Output {{
+                let a0 = self[0];
+                let a1 = self[1];
+                let a2 = self[2];
+                let a3 = self[3];
+
+                let b0 = rhs[0];
+                let b1 = rhs[1];
+                let b2 = rhs[2];
+                let b3 = rhs[3];
+
+                {mat}::from_columns(
+                    a0 * b0[0] + a1 * b0[1] + a2 * b0[2] + a3 * b0[3],
+                    a0 * b1[0] + a1 * b1[1] + a2 * b1[2] + a3 * b1[3],
+                    a0 * b2[0] + a1 * b2[1] + a2 * b2[2] + a3 * b2[3],
+                    a0 * b3[0] + a1 * b3[1] + a2 * b3[2] + a3 * b3[3],
+                )
+            }}
+        }}
+
+        {simd_prefix}
+        impl Mul<{vec4}> for {mat} {{
+            type Output = {vec4};
+
+            fn mul(self, rhs: {vec4}) -> Self::Output {{
+                let v0 = rhs[0];
+                let v1 = rhs[1];
+                let v2 = rhs[2];
+                let v3 = rhs[3];
+                let m0 = self[0];
+                let m1 = self[1];
+                let m2 = self[2];
+                let m3 = self[3];
+
+                {vec4}::new(
+                    m0[0] * v0 + m1[0] * v1 + m2[0] * v2 + m3[0] * v3,
+                    m0[1] * v0 + m1[1] * v1 + m2[1] * v2 + m3[1] * v3,
+                    m0[2] * v0 + m1[2] * v1 + m2[2] * v2 + m3[2] * v3,
+                    m0[3] * v0 + m1[3] * v1 + m2[3] * v2 + m3[3] * v3,
+                )
+            }}
+        }}
+
+        impl Debug for {mat} {{
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {{
+                f.debug_struct(\"{mat}\")
+                    .field(\"c0: \", &self[0])
+                    .field(\"c1: \", &self[1])
+                    .field(\"c2: \", &self[2])
+                    .field(\"c3: \", &self[3])
+                    .finish()
+            }}
+        }}
+
+        // Row by row, `{{:#}}` prints one aligned row per line.
+        impl Display for {mat} {{
+            fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {{
+                crate::format::fmt_rows(f, &self.to_rows_array_2d())
+            }}
+        }}
+
+        impl FromStr for {mat} {{
+            type Err = crate::ParseMathError;
+
+            fn from_str(s: &str) -> Result<{mat}, crate::ParseMathError> {{
+                let [r0, r1, r2, r3] = crate::format::parse_rows(s)?;
+                Ok({mat}::from_rows({vec4}::from_array(r0), {vec4}::from_array(r1), {vec4}::from_array(r2), {vec4}::from_array(r3)))
+            }}
+        }}
+
+        impl Default for {mat} {{
+            #[inline]
+            fn default() -> {mat} {{
+                {mat}::IDENTITY
+            }}
+        }}
+
+        {precision_conversion}
+        {ops}
+        {conversions}
+        "
+    )
+}
+
+// Flat and nested array conversions. The `cols` functions and the `From`/`AsRef` impls use
+// the column-major memory layout, the `rows` functions the row-major order.
+fn create_matrix_conversion_impls(kind: VecKind) -> String {
+    let mat = kind.mat();
+    let vec4 = kind.vec(4);
+    let scalar = kind.scalar;
+    let array_type = format!("[{scalar}; 16]");
+    let array_2d_type = format!("[[{scalar}; 4]; 4]");
+    let cols_elems = iproduct!(0..4, VEC_FIELDS)
+        .map(|(c, f)| format!("self.c{c}.{f}"))
+        .join(",");
+    let rows_elems = iproduct!(VEC_FIELDS, 0..4)
+        .map(|(f, c)| format!("self.c{c}.{f}"))
+        .join(",");
+    let rows_2d_elems = VEC_FIELDS
+        .iter()
+        .map(|f| format!("[{}]", (0..4).map(|c| format!("self.c{c}.{f}")).join(",")))
+        .join(",");
+    let columns_from = |index: fn(usize, usize) -> usize, name: &str| {
+        (0..4)
+            .map(|c| format!("{vec4}::new({})", (0..4).map(|r| format!("{name}[{}]", index(c, r))).join(",")))
+            .join(",")
+    };
+    let cols_array_columns = columns_from(|c, r| c * 4 + r, "m");
+    let rows_array_columns = columns_from(|c, r| r * 4 + c, "m");
+    let cols_slice_columns = columns_from(|c, r| c * 4 + r, "slice");
+    let rows_columns = VEC_FIELDS
+        .iter()
+        .map(|f| format!("{vec4}::new(r0.{f}, r1.{f}, r2.{f}, r3.{f})"))
+        .join(",");
+
+    format!(
+        "
+        impl {mat} {{
+            #[inline]
+            pub const fn from_rows(r0: {vec4}, r1: {vec4}, r2: {vec4}, r3: {vec4}) -> {mat} {{
+                {mat}::from_columns({rows_columns})
+            }}
+
+            #[inline]
+            pub const fn from_cols_array(m: {array_type}) -> {mat} {{
+                {mat}::from_columns({cols_array_columns})
+            }}
+
+            #[inline]
+            pub const fn to_cols_array(self) -> {array_type} {{ [{cols_elems}] }}
+
+            #[inline]
+            pub const fn from_cols_array_2d(m: {array_2d_type}) -> {mat} {{
+                {mat}::from_columns({vec4}::from_array(m[0]), {vec4}::from_array(m[1]), {vec4}::from_array(m[2]), {vec4}::from_array(m[3]))
+            }}
+
+            #[inline]
+            pub const fn to_cols_array_2d(self) -> {array_2d_type} {{
+                [self.c0.to_array(), self.c1.to_array(), self.c2.to_array(), self.c3.to_array()]
+            }}
+
+            #[inline]
+            pub const fn from_rows_array(m: {array_type}) -> {mat} {{
+                {mat}::from_columns({rows_array_columns})
+            }}
+
+            #[inline]
+            pub const fn to_rows_array(self) -> {array_type} {{ [{rows_elems}] }}
+
+            #[inline]
+            pub const fn to_rows_array_2d(self) -> {array_2d_type} {{ [{rows_2d_elems}] }}
+
+            // Reads the first 16 values in column-major order, panics if the slice is shorter.
+            #[inline]
+            pub fn from_cols_slice(slice: &[{scalar}]) -> {mat} {{
+                {mat}::from_columns({cols_slice_columns})
+            }}
+
+            // Writes to the first 16 values in column-major order, panics if the slice is shorter.
+            #[inline]
+            pub fn write_cols_to_slice(self, slice: &mut [{scalar}]) {{
+                slice[..16].copy_from_slice(&self.to_cols_array());
+            }}
+        }}
+
+        impl From<{array_type}> for {mat} {{
+            #[inline]
+            fn from(m: {array_type}) -> {mat} {{ {mat}::from_cols_array(m) }}
+        }}
+
+        impl From<{mat}> for {array_type} {{
+            #[inline]
+            fn from(m: {mat}) -> {array_type} {{ m.to_cols_array() }}
+        }}
+
+        impl From<{array_2d_type}> for {mat} {{
+            #[inline]
+            fn from(m: {array_2d_type}) -> {mat} {{ {mat}::from_cols_array_2d(m) }}
+        }}
+
+        impl From<{mat}> for {array_2d_type} {{
+            #[inline]
+            fn from(m: {mat}) -> {array_2d_type} {{ m.to_cols_array_2d() }}
+        }}
+
+        impl AsRef<{array_type}> for {mat} {{
+            #[inline]
+            fn as_ref(&self) -> &{array_type} {{
+                unsafe {{ &*(self as *const {mat} as *const {array_type}) }}
+            }}
+        }}
+
+        impl AsMut<{array_type}> for {mat} {{
+            #[inline]
+            fn as_mut(&mut self) -> &mut {array_type} {{
+                unsafe {{ &mut *(self as *mut {mat} as *mut {array_type}) }}
+            }}
+        }}
+        "
+    )
+}
+
+fn create_matrix_ops(kind: VecKind) -> String {
+    let mat = kind.mat();
+    let vec4 = kind.vec(4);
+    let scalar = kind.scalar;
+
+    let component_ops = [("Add", "+"), ("Sub", "-")]
+        .iter()
+        .map(|(operator_trait, operator_token)| {
+            let func_name = operator_trait.to_lowercase();
+
+            format!(
+                "
+                impl core::ops::{operator_trait}<{mat}> for {mat} {{
+                    type Output = {mat};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: {mat}) -> {mat} {{
+                        {mat}::from_columns(
+                            self[0] {operator_token} rhs[0],
+                            self[1] {operator_token} rhs[1],
+                            self[2] {operator_token} rhs[2],
+                            self[3] {operator_token} rhs[3],
+                        )
+                    }}
+                }}
+
+                impl core::ops::{operator_trait}Assign<{mat}> for {mat} {{
+                    #[inline]
+                    fn {func_name}_assign(&mut self, rhs: {mat}) {{
+                        *self = *self {operator_token} rhs
+                    }}
+                }}
+                "
+            )
+        })
+        .join("");
+
+    let ref_ops = [
+        create_ref_op_impls("Add", "+", &mat, &mat),
+        create_ref_op_impls("Sub", "-", &mat, &mat),
+        create_ref_op_impls("Mul", "*", &mat, &mat),
+        create_ref_op_impls("Mul", "*", &mat, scalar),
+    ]
+    .join("");
+
+    let sum_product = create_sum_product_impls(&mat, "identity");
+
+    format!(
+        "
+        {component_ops}
+        {ref_ops}
+        {sum_product}
+
+        impl core::ops::Neg for {mat} {{
+            type Output = {mat};
+
+            #[inline]
+            fn neg(self) -> {mat} {{
+                {mat}::from_columns(-self[0], -self[1], -self[2], -self[3])
+            }}
+        }}
+
+        impl core::ops::MulAssign<{mat}> for {mat} {{
+            #[inline]
+            fn mul_assign(&mut self, rhs: {mat}) {{
+                *self = *self * rhs
+            }}
+        }}
+
+        impl core::ops::MulAssign<{scalar}> for {mat} {{
+            #[inline]
+            fn mul_assign(&mut self, rhs: {scalar}) {{
+                *self = *self * rhs
+            }}
+        }}
+
+        impl core::ops::Mul<{mat}> for {scalar} {{
+            type Output = {mat};
+
+            #[inline]
+            fn mul(self, rhs: {mat}) -> {mat} {{ rhs * self }}
+        }}
+
+        impl core::ops::Mul<&{vec4}> for {mat} {{
+            type Output = {vec4};
+
+            #[inline]
+            fn mul(self, rhs: &{vec4}) -> {vec4} {{ self * *rhs }}
+        }}
+
+        impl core::ops::Mul<&{vec4}> for &{mat} {{
+            type Output = {vec4};
+
+            #[inline]
+            fn mul(self, rhs: &{vec4}) -> {vec4} {{ *self * *rhs }}
+        }}
+        "
+    )
+}
+
+fn create_matrix_approx_func(kind: VecKind) -> String {
+    let mat = kind.mat();
+    let vec4 = kind.vec(4);
+
+    format!(
+        "
+        pub fn approx(x: {mat}, y: {mat}) -> bool {{
+            {vec4}::approx(x[0], y[0])
+                && {vec4}::approx(x[1], y[1])
+                && {vec4}::approx(x[2], y[2])
+                && {vec4}::approx(x[3], y[3])
+        }}
+        "
+    )
+}
+
+fn create_matrix_precision_funcs(kind: VecKind) -> String {
+    let target = if kind.is_f32() { F64_KIND } else { F32_KIND };
+    let target_mat = target.mat();
+    let target_scalar = target.scalar;
+
+    format!(
+        "
+        #[inline]
+        pub fn as_{target_scalar}(self) -> {target_mat} {{
+            {target_mat}::from_columns(
+                self.c0.as_{target_scalar}(),
+                self.c1.as_{target_scalar}(),
+                self.c2.as_{target_scalar}(),
+                self.c3.as_{target_scalar}(),
+            )
+        }}
+        "
+    )
+}
+
+fn create_matrix_precision_conversion_impl(kind: VecKind) -> String {
+    if kind.is_f32() {
+        return String::new();
+    }
+
+    let mat = kind.mat();
+    let source = F32_KIND.mat();
+
+    format!(
+        "
+        impl From<{source}> for {mat} {{
+            #[inline]
+            fn from(m: {source}) -> {mat} {{ m.as_f64() }}
+        }}
+        "
+    )
+}
+
+fn create_wide_vectors() -> String {
+    let mut result = String::new();
+
+    result
+        .write_str("/*** This code was generated automatically ***/\n\n")
+        .unwrap();
+
+    for lanes in [4, 8] {
+        result.write_str(create_wide_mask(lanes).as_str()).unwrap();
+        result.write_str(create_wide_vector(lanes).as_str()).unwrap();
+    }
+
+    result
+}
+
+fn create_wide_mask(lanes: usize) -> String {
+    let ty = format!("Mask{lanes}");
+    let all_bits = format!("{:#x}", (1u64 << lanes) - 1);
+
+    let compares = ["eq", "ne", "lt", "le", "gt", "ge"]
+        .iter()
+        .map(|cmp| {
+            format!(
+                "
+                #[inline]
+                pub fn cmp{cmp}(a: [f32; {lanes}], b: [f32; {lanes}]) -> {ty} {{
+                    {ty} {{ bits: compare_lanes::<{lanes}>(|i| load_lanes(&a, i).cmp{cmp}(load_lanes(&b, i))) }}
+                }}
+                "
+            )
+        })
+        .join("");
+
+    let bit_ops = BITWISE_OPS
+        .iter()
+        .map(|(op_trait, op_token)| {
+            let func_name = op_trait.to_lowercase();
+
+            format!(
+                "
+                impl core::ops::{op_trait}<{ty}> for {ty} {{
+                    type Output = {ty};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: {ty}) -> {ty} {{ {ty} {{ bits: self.bits {op_token} rhs.bits }} }}
+                }}
+                "
+            )
+        })
+        .join("");
+
+    format!(
+        "
+        #[derive(PartialEq, Eq, Hash, Copy, Clone, Debug, Default)]
+        pub struct {ty} {{
+            bits: u32,
+        }}
+
+        impl {ty} {{
+            #[inline]
+            pub fn new(lanes: [bool; {lanes}]) -> {ty} {{
+                {ty}::from_bitmask(lanes.iter().enumerate().fold(0, |bits, (i, l)| bits | (*l as u32) << i))
+            }}
+
+            #[inline]
+            pub fn splat(value: bool) -> {ty} {{ {ty}::from_bitmask(if value {{ {all_bits} }} else {{ 0 }}) }}
+
+            #[inline]
+            pub fn from_bitmask(bits: u32) -> {ty} {{ {ty} {{ bits: bits & {all_bits} }} }}
+
+            #[inline]
+            pub fn bitmask(self) -> u32 {{ self.bits }}
+
+            #[inline]
+            pub fn any(self) -> bool {{ self.bits != 0 }}
+
+            #[inline]
+            pub fn all(self) -> bool {{ self.bits == {all_bits} }}
+
+            #[inline]
+            pub fn test(self, lane: usize) -> bool {{
+                assert!(lane < {lanes});
+                self.bits >> lane & 1 != 0
+            }}
+
+            #[inline]
+            pub fn set(&mut self, lane: usize, value: bool) {{
+                assert!(lane < {lanes});
+                self.bits = self.bits & !(1 << lane) | (value as u32) << lane;
+            }}
+
+            {compares}
+        }}
+
+        {bit_ops}
+
+        impl core::ops::Not for {ty} {{
+            type Output = {ty};
+
+            #[inline]
+            fn not(self) -> {ty} {{ {ty}::from_bitmask(!self.bits) }}
+        }}
+        "
+    )
+}
+
+// Structure-of-arrays Vector3s, `lanes` of each component stored next to each other.
+fn create_wide_vector(lanes: usize) -> String {
+    let ty = format!("Vector3x{lanes}");
+    let mask = format!("Mask{lanes}");
+    let scalars = format!("[f32; {lanes}]");
+
+    let vector_ops = ARITHMETIC_OPS
+        .iter()
+        .take(4)
+        .map(|(op_trait, op_token)| {
+            let func_name = op_trait.to_lowercase();
+
+            format!(
+                "
+                impl {op_trait}<{ty}> for {ty} {{
+                    type Output = {ty};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: {ty}) -> {ty} {{
+                        {ty}::from_chunks(|i| self.chunk(i).zip(rhs.chunk(i), |a, b| a {op_token} b))
+                    }}
+                }}
+
+                impl {op_trait}Assign<{ty}> for {ty} {{
+                    #[inline]
+                    fn {func_name}_assign(&mut self, rhs: {ty}) {{ *self = *self {op_token} rhs; }}
+                }}
+                "
+            )
+        })
+        .join("");
+
+    let scalar_ops = [("Mul", "*"), ("Div", "/")]
+        .iter()
+        .map(|(op_trait, op_token)| {
+            let func_name = op_trait.to_lowercase();
+
+            format!(
+                "
+                impl {op_trait}<f32> for {ty} {{
+                    type Output = {ty};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: f32) -> {ty} {{
+                        {ty}::from_chunks(|i| self.chunk(i).map(|v| v {op_token} rhs))
+                    }}
+                }}
+
+                impl {op_trait}Assign<f32> for {ty} {{
+                    #[inline]
+                    fn {func_name}_assign(&mut self, rhs: f32) {{ *self = *self {op_token} rhs; }}
+                }}
+
+                impl {op_trait}<{scalars}> for {ty} {{
+                    type Output = {ty};
+
+                    #[inline]
+                    fn {func_name}(self, rhs: {scalars}) -> {ty} {{
+                        {ty}::from_chunks(|i| {{
+                            let s = load_lanes(&rhs, i);
+                            self.chunk(i).map(|v| v {op_token} s)
+                        }})
+                    }}
+                }}
+                "
+            )
+        })
+        .join("");
+
+    format!(
+        "
+        #[derive(PartialEq, Copy, Clone, Debug, Default)]
+        #[repr(C)]
+        pub struct {ty} {{
+            pub x: {scalars},
+            pub y: {scalars},
+            pub z: {scalars},
+        }}
+
+        impl {ty} {{
+            pub const LANES: usize = {lanes};
+
+            #[inline]
+            pub const fn new(x: {scalars}, y: {scalars}, z: {scalars}) -> {ty} {{ {ty} {{ x, y, z }} }}
+
+            #[inline]
+            pub const fn splat(v: Vector3) -> {ty} {{ {ty}::new([v.x; {lanes}], [v.y; {lanes}], [v.z; {lanes}]) }}
+
+            #[inline]
+            pub fn zero() -> {ty} {{ {ty}::splat(Vector3::zero()) }}
+
+            #[inline]
+            pub fn one() -> {ty} {{ {ty}::splat(Vector3::one()) }}
+
+            // Reads the first {lanes} vectors of `src`, panics if it is shorter.
+            pub fn gather(src: &[Vector3]) -> {ty} {{
+                assert!(src.len() >= {lanes});
+                let mut result = {ty}::zero();
+
+                for (i, v) in src[..{lanes}].iter().enumerate() {{
+                    result.set_lane(i, *v);
+                }}
+
+                result
+            }}
+
+            // Writes the lanes to the first {lanes} vectors of `dst`, panics if it is shorter.
+            pub fn scatter(self, dst: &mut [Vector3]) {{
+                assert!(dst.len() >= {lanes});
+
+                for (i, v) in dst[..{lanes}].iter_mut().enumerate() {{
+                    *v = self.lane(i);
+                }}
+            }}
+
+            #[inline]
+            pub fn lane(&self, i: usize) -> Vector3 {{ Vector3::new(self.x[i], self.y[i], self.z[i]) }}
+
+            #[inline]
+            pub fn set_lane(&mut self, i: usize, v: Vector3) {{
+                self.x[i] = v.x;
+                self.y[i] = v.y;
+                self.z[i] = v.z;
+            }}
+
+            #[inline]
+            pub fn dot(l: {ty}, r: {ty}) -> {scalars} {{
+                map_lanes(|i| Chunk3::dot(l.chunk(i), r.chunk(i)))
+            }}
+
+            #[inline]
+            pub fn cross(l: {ty}, r: {ty}) -> {ty} {{
+                {ty}::from_chunks(|i| Chunk3::cross(l.chunk(i), r.chunk(i)))
+            }}
+
+            #[inline]
+            pub fn length_sqr(self) -> {scalars} {{ {ty}::dot(self, self) }}
+
+            #[inline]
+            pub fn length(self) -> {scalars} {{
+                map_lanes(|i| {{
+                    let c = self.chunk(i);
+                    Chunk3::dot(c, c).sqrt()
+                }})
+            }}
+
+            #[inline]
+            pub fn normalized(self) -> {ty} {{
+                {ty}::from_chunks(|i| {{
+                    let c = self.chunk(i);
+                    let length = Chunk3::dot(c, c).sqrt();
+                    c.map(|v| v / length)
+                }})
+            }}
+
+            #[inline]
+            pub fn select(mask: {mask}, a: {ty}, b: {ty}) -> {ty} {{
+                {ty}::from_chunks(|i| Chunk3::select(mask_lanes(mask.bitmask(), i), a.chunk(i), b.chunk(i)))
+            }}
+
+            // Lanes where all three components compare equal.
+            #[inline]
+            pub fn cmpeq(self, rhs: {ty}) -> {mask} {{
+                {mask}::from_bitmask(compare_lanes::<{lanes}>(|i| self.chunk(i).cmpeq(rhs.chunk(i))))
+            }}
+
+            #[inline]
+            pub fn cmpne(self, rhs: {ty}) -> {mask} {{ !self.cmpeq(rhs) }}
+
+            #[inline]
+            fn chunk(&self, i: usize) -> Chunk3 {{ Chunk3::load(&self.x, &self.y, &self.z, i) }}
+
+            #[inline]
+            fn from_chunks(f: impl Fn(usize) -> Chunk3) -> {ty} {{
+                let mut result = {ty}::zero();
+
+                for i in (0..{lanes}).step_by(4) {{
+                    f(i).store(&mut result.x, &mut result.y, &mut result.z, i);
+                }}
+
+                result
+            }}
+        }}
+
+        {vector_ops}
+
+        {scalar_ops}
+
+        impl Mul<{ty}> for f32 {{
+            type Output = {ty};
+
+            #[inline]
+            fn mul(self, rhs: {ty}) -> {ty} {{ rhs * self }}
+        }}
+
+        impl Neg for {ty} {{
+            type Output = {ty};
+
+            #[inline]
+            fn neg(self) -> {ty} {{ {ty}::from_chunks(|i| self.chunk(i).map(|v| -v)) }}
+        }}
+        "
+    )
+}
+
+fn create_half_vectors() -> String {
+    let mut result = String::new();
+
+    result
+        .write_str("/*** This code was generated automatically ***/\n\n")
+        .unwrap();
+
+    for dim in [2, 3, 4] {
+        result.write_str(create_half_vector(dim).as_str()).unwrap();
+    }
+
+    result
+}
+
+fn create_half_vector(dim: usize) -> String {
+    let ty = format!("HalfVector{dim}");
+    let vec_ty = format!("Vector{dim}");
+    let fields = VEC_FIELDS.iter().take(dim);
+
+    let field_defs = fields.clone().map(|f| format!("pub {f}: f16,")).join("\n");
+    let params_list = fields.clone().map(|f| format!("{f}: f16")).join(",");
+    let args_list = fields.clone().join(",");
+    let to_half = fields
+        .clone()
+        .map(|f| format!("f16::from_f32(v.{f})"))
+        .join(",");
+    let to_full = fields.map(|f| format!("v.{f}.to_f32()")).join(",");
+    let all_args = (0..dim).map(|_| "value").join(",");
+    let consts = create_half_vec_consts(dim);
+    let conversions = create_conversion_impls(&ty, "f16", &VEC_FIELDS[..dim]);
+
+    format!(
+        "
+        #[repr(C)]
+        #[derive(PartialEq, Copy, Clone, Debug, Default)]
+        pub struct {ty} {{
+            {field_defs}
+        }}
+
+        impl {ty} {{
+            #[inline]
+            pub const fn new({params_list}) -> {ty} {{ {ty} {{ {args_list} }} }}
+
+            #[inline]
+            pub const fn all(value: f16) -> {ty} {{ {ty}::new({all_args}) }}
+
+            {consts}
+
+            pub fn from_vector_slice(src: &[{vec_ty}], dst: &mut [{ty}]) {{
+                assert_eq!(src.len(), dst.len());
+
+                f32_to_f16_slice(cast::as_slice(src), cast::as_mut_slice(dst));
+            }}
+
+            pub fn to_vector_slice(src: &[{ty}], dst: &mut [{vec_ty}]) {{
+                assert_eq!(src.len(), dst.len());
+
+                f16_to_f32_slice(cast::as_slice(src), cast::as_mut_slice(dst));
+            }}
+        }}
+
+        impl From<{vec_ty}> for {ty} {{
+            #[inline]
+            fn from(v: {vec_ty}) -> {ty} {{ {ty}::new({to_half}) }}
+        }}
+
+        impl From<{ty}> for {vec_ty} {{
+            #[inline]
+            fn from(v: {ty}) -> {vec_ty} {{ {vec_ty}::new({to_full}) }}
+        }}
+
+        {conversions}
+        "
+    )
+}
+
+// Same set as `create_vec_consts`, spelled with the `f16` constants since there are no
+// f16 literals.
+fn create_half_vec_consts(dim: usize) -> String {
+    let ty = format!("HalfVector{dim}");
+    let mut result = [
+        ("ZERO", "f16::ZERO"),
+        ("ONE", "f16::ONE"),
+        ("NEG_ONE", "f16::NEG_ONE"),
+        ("MIN", "f16::MIN"),
+        ("MAX", "f16::MAX"),
+        ("NAN", "f16::NAN"),
+        ("INFINITY", "f16::INFINITY"),
+        ("NEG_INFINITY", "f16::NEG_INFINITY"),
+    ]
+    .iter()
+    .map(|(name, value)| format!("pub const {name}: {ty} = {ty}::all({value});\n"))
+    .join("");
+
+    for (axis, field) in VEC_FIELDS.iter().take(dim).enumerate() {
+        let unit = |one: &str| {
+            (0..dim)
+                .map(|i| if i == axis { one } else { "f16::ZERO" })
+                .join(",")
+        };
+        let name = field.to_uppercase();
+
+        result
+            .write_fmt(format_args!(
+                "pub const {name}: {ty} = {ty}::new({});\npub const NEG_{name}: {ty} = {ty}::new({});\n",
+                unit("f16::ONE"),
+                unit("f16::NEG_ONE")
+            ))
+            .unwrap();
+    }
+
+    result
+}
+
+fn create_serde_impls() -> String {
+    let mut result = String::new();
+
+    result
+        .write_str("/*** This code was generated automatically ***/\n\n")
+        .unwrap();
+
+    for (kind, dim) in iproduct!(ALL_KINDS, [2, 3, 4]) {
+        let ty = kind.vec(dim);
+        result
+            .write_str(create_serde_array_impl(&ty, kind.scalar, dim).as_str())
+            .unwrap();
+    }
+
+    for dim in [2, 3, 4] {
+        result
+            .write_str(create_serde_array_impl(&format!("BVector{dim}"), "bool", dim).as_str())
+            .unwrap();
+        result
+            .write_str(create_serde_array_impl(&format!("HalfVector{dim}"), "f16", dim).as_str())
+            .unwrap();
+    }
+
+    result
+        .write_str(create_serde_array_impl("Vector3A", "f32", 3).as_str())
+        .unwrap();
+    result
+        .write_str(create_serde_array_impl("Color", "f32", 4).as_str())
+        .unwrap();
+
+    for kind in FLOAT_KINDS {
+        result.write_str(create_matrix_serde_impl(kind).as_str()).unwrap();
+    }
+
+    result
+}
+
+// Serialized as a fixed size array of the fields, `[x, y, z]` in text formats and without a
+// length prefix in binary ones.
+fn create_serde_array_impl(ty: &str, scalar: &str, dim: usize) -> String {
+    format!(
+        "
+        impl Serialize for {ty} {{
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                self.to_array().serialize(serializer)
+            }}
+        }}
+
+        impl<'de> Deserialize<'de> for {ty} {{
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<{ty}, D::Error> {{
+                <[{scalar}; {dim}]>::deserialize(deserializer).map({ty}::from_array)
+            }}
+        }}
+        "
+    )
+}
+
+// A flat column-major array of 16 values. `nested_columns` provides the `[[..; 4]; 4]` form.
+fn create_matrix_serde_impl(kind: VecKind) -> String {
+    let mat = kind.mat();
+    let vec4 = kind.vec(4);
+    let scalar = kind.scalar;
+
+    format!(
+        "
+        impl Serialize for {mat} {{
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                self.to_cols_array().serialize(serializer)
+            }}
+        }}
+
+        impl<'de> Deserialize<'de> for {mat} {{
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<{mat}, D::Error> {{
+                <[{scalar}; 16]>::deserialize(deserializer).map({mat}::from_cols_array)
+            }}
+        }}
+
+        impl nested_columns::MatrixColumns for {mat} {{
+            type Column = {vec4};
+
+            #[inline]
+            fn to_columns(&self) -> [{vec4}; 4] {{
+                [self[0], self[1], self[2], self[3]]
+            }}
+
+            #[inline]
+            fn from_columns_array(columns: [{vec4}; 4]) -> {mat} {{
+                let [c0, c1, c2, c3] = columns;
+                {mat}::from_columns(c0, c1, c2, c3)
+            }}
+        }}
+        "
+    )
+}
+
+fn create_cast_impls() -> String {
+    let mut result = String::new();
+    let mut components = Vec::new();
+
+    result
+        .write_str("/*** This code was generated automatically ***/\n\n")
+        .unwrap();
+
+    for (kind, dim) in iproduct!(ALL_KINDS, [2, 3, 4]) {
+        components.push((kind.vec(dim), kind.scalar, dim));
+    }
+
+    for kind in FLOAT_KINDS {
+        components.push((kind.mat(), kind.scalar, 16));
+    }
+
+    for dim in [2, 3, 4] {
+        components.push((format!("HalfVector{dim}"), "f16", dim));
+    }
+
+    components.push(("Color".to_string(), "f32", 4));
+
+    for (ty, scalar, count) in &components {
+        result
+            .write_fmt(format_args!(
+                "
+                impl sealed::Sealed for crate::{ty} {{}}
+
+                impl Components for crate::{ty} {{
+                    type Scalar = {scalar};
+                    const COUNT: usize = {count};
+                }}
+                "
+            ))
+            .unwrap();
+    }
+
+    // Any bit pattern is valid for these, so they are `Pod`. Bools and Vector3A, whose hidden
+    // w has to stay 0, only allow zeroing.
+    let pod_types = components
+        .into_iter()
+        .map(|(ty, _, _)| ty)
+        .chain(["f16".to_string(), "Vector3x4".to_string(), "Vector3x8".to_string()]);
+    let zeroable_types = ["BVector2", "BVector3", "BVector4", "Vector3A"];
+
+    for ty in pod_types {
+        result
+            .write_fmt(format_args!(
+                "
+                #[cfg(feature = \"bytemuck\")]
+                unsafe impl bytemuck::Zeroable for crate::{ty} {{}}
+                #[cfg(feature = \"bytemuck\")]
+                unsafe impl bytemuck::Pod for crate::{ty} {{}}
+                "
+            ))
+            .unwrap();
+    }
+
+    for ty in zeroable_types {
+        result
+            .write_fmt(format_args!(
+                "
+                #[cfg(feature = \"bytemuck\")]
+                unsafe impl bytemuck::Zeroable for crate::{ty} {{}}
+                "
+            ))
+            .unwrap();
+    }
+
+    result
+}
+
+fn create_interop_impls() -> String {
+    let mut result = String::new();
+
+    result
+        .write_str("/*** This code was generated automatically ***/\n\n")
+        .unwrap();
+
+    for (kind, dim) in iproduct!(ALL_KINDS, [2, 3, 4]) {
+        let ty = kind.vec(dim);
+        let scalar = kind.scalar;
+        let fields = &VEC_FIELDS[..dim];
+        let mint_fields = fields.iter().map(|f| format!("{f}: v.{f}")).join(",");
+
+        for (feature, other, other_new) in [
+            ("mint", format!("mint::Vector{dim}<{scalar}>"), format!("mint::Vector{dim} {{ {mint_fields} }}")),
+            ("glam", format!("glam::{}Vec{dim}", kind.prefix), format!("glam::{}Vec{dim}::new", kind.prefix)),
+            ("nalgebra", format!("nalgebra::Vector{dim}<{scalar}>"), format!("nalgebra::Vector{dim}::new")),
+        ] {
+            result
+                .write_str(create_vector_interop(feature, &ty, &other, &other_new, fields).as_str())
+                .unwrap();
+        }
+    }
+
+    let vec3_fields = &VEC_FIELDS[..3];
+    result
+        .write_str(create_vector_interop("mint", "Vector3A", "mint::Vector3<f32>", "mint::Vector3 { x: v.x, y: v.y, z: v.z }", vec3_fields).as_str())
+        .unwrap();
+    result
+        .write_str(create_vector_interop("glam", "Vector3A", "glam::Vec3A", "glam::Vec3A::new", vec3_fields).as_str())
+        .unwrap();
+
+    for kind in FLOAT_KINDS {
+        result.write_str(create_matrix_interop(kind).as_str()).unwrap();
+    }
+
+    result
+}
+
+// `other_new` is either a constructor taking the components in order or a struct literal
+// reading them from `v`.
+fn create_vector_interop(feature: &str, ty: &str, other: &str, other_new: &str, fields: &[&str]) -> String {
+    let args = fields.iter().map(|f| format!("v.{f}")).join(",");
+    let to_other = if other_new.ends_with('}') {
+        other_new.to_string()
+    } else {
+        format!("{other_new}({args})")
+    };
+
+    format!(
+        "
+        #[cfg(feature = \"{feature}\")]
+        impl From<{ty}> for {other} {{
+            #[inline]
+            fn from(v: {ty}) -> {other} {{ {to_other} }}
+        }}
+
+        #[cfg(feature = \"{feature}\")]
+        impl From<{other}> for {ty} {{
+            #[inline]
+            fn from(v: {other}) -> {ty} {{ {ty}::new({args}) }}
+        }}
         "
     )
-}
\ No newline at end of file
+}
+
+// All three libraries store matrices column-major, so columns map to columns.
+fn create_matrix_interop(kind: VecKind) -> String {
+    let mat = kind.mat();
+    let vec4 = kind.vec(4);
+    let scalar = kind.scalar;
+    let glam_mat = format!("glam::{}Mat4", kind.prefix);
+    let nalgebra_columns = (0..4)
+        .map(|c| format!("{vec4}::new(m[(0, {c})], m[(1, {c})], m[(2, {c})], m[(3, {c})])"))
+        .join(",");
+
+    format!(
+        "
+        #[cfg(feature = \"mint\")]
+        impl From<{mat}> for mint::ColumnMatrix4<{scalar}> {{
+            #[inline]
+            fn from(m: {mat}) -> mint::ColumnMatrix4<{scalar}> {{
+                mint::ColumnMatrix4 {{ x: m[0].into(), y: m[1].into(), z: m[2].into(), w: m[3].into() }}
+            }}
+        }}
+
+        #[cfg(feature = \"mint\")]
+        impl From<mint::ColumnMatrix4<{scalar}>> for {mat} {{
+            #[inline]
+            fn from(m: mint::ColumnMatrix4<{scalar}>) -> {mat} {{
+                {mat}::from_columns(m.x.into(), m.y.into(), m.z.into(), m.w.into())
+            }}
+        }}
+
+        #[cfg(feature = \"glam\")]
+        impl From<{mat}> for {glam_mat} {{
+            #[inline]
+            fn from(m: {mat}) -> {glam_mat} {{
+                {glam_mat}::from_cols(m[0].into(), m[1].into(), m[2].into(), m[3].into())
+            }}
+        }}
+
+        #[cfg(feature = \"glam\")]
+        impl From<{glam_mat}> for {mat} {{
+            #[inline]
+            fn from(m: {glam_mat}) -> {mat} {{
+                {mat}::from_columns(m.x_axis.into(), m.y_axis.into(), m.z_axis.into(), m.w_axis.into())
+            }}
+        }}
+
+        #[cfg(feature = \"nalgebra\")]
+        impl From<{mat}> for nalgebra::Matrix4<{scalar}> {{
+            #[inline]
+            fn from(m: {mat}) -> nalgebra::Matrix4<{scalar}> {{
+                nalgebra::Matrix4::from_columns(&[m[0].into(), m[1].into(), m[2].into(), m[3].into()])
+            }}
+        }}
+
+        #[cfg(feature = \"nalgebra\")]
+        impl From<nalgebra::Matrix4<{scalar}>> for {mat} {{
+            #[inline]
+            fn from(m: nalgebra::Matrix4<{scalar}>) -> {mat} {{
+                {mat}::from_columns({nalgebra_columns})
+            }}
+        }}
+        "
+    )
+}
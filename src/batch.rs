@@ -0,0 +1,168 @@
+use crate::{active_simd_backend, Matrix4x4, Vector3, Vector4};
+#[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+use crate::SimdBackend;
+
+// Batch transforms dispatch at runtime to the best kernel for the CPU, see `SimdBackend`.
+// The scalar path runs four elements at a time in structure-of-arrays form, so every
+// matrix column is applied to four x, y, z (and w) lanes with one Vector4 op. The sums
+// are accumulated in the same order as `Matrix4x4 * Vector4`, so results match the
+// per-element product exactly. Leftover elements go through the regular product.
+
+pub fn transform_points(m: &Matrix4x4, src: &[Vector3], dst: &mut [Vector3]) {
+    assert_eq!(src.len(), dst.len());
+    transform_vector3s(m, src, dst, Mode::Point);
+}
+
+pub fn transform_points_in_place(m: &Matrix4x4, points: &mut [Vector3]) {
+    transform_vector3s_in_place(m, points, Mode::Point);
+}
+
+// Like `transform_points` but divides the result by w, as needed for projection matrices.
+pub fn transform_points_perspective(m: &Matrix4x4, src: &[Vector3], dst: &mut [Vector3]) {
+    assert_eq!(src.len(), dst.len());
+    transform_vector3s(m, src, dst, Mode::PerspectiveDivide);
+}
+
+pub fn transform_points_perspective_in_place(m: &Matrix4x4, points: &mut [Vector3]) {
+    transform_vector3s_in_place(m, points, Mode::PerspectiveDivide);
+}
+
+// Transforms directions, ignoring the translation part of the matrix.
+pub fn transform_vectors(m: &Matrix4x4, src: &[Vector3], dst: &mut [Vector3]) {
+    assert_eq!(src.len(), dst.len());
+    transform_vector3s(m, src, dst, Mode::Direction);
+}
+
+pub fn transform_vectors_in_place(m: &Matrix4x4, vectors: &mut [Vector3]) {
+    transform_vector3s_in_place(m, vectors, Mode::Direction);
+}
+
+pub fn transform_vec4s(m: &Matrix4x4, src: &[Vector4], dst: &mut [Vector4]) {
+    assert_eq!(src.len(), dst.len());
+
+    match active_simd_backend() {
+        #[cfg(target_arch = "x86_64")]
+        SimdBackend::Avx2Fma => unsafe { crate::simd::batch_x86::transform_vec4s_avx2_fma(m, src, dst) },
+        #[cfg(target_arch = "x86_64")]
+        SimdBackend::Sse41 => unsafe { crate::simd::batch_x86::transform_vec4s_sse41(m, src, dst) },
+        #[cfg(target_arch = "aarch64")]
+        SimdBackend::Neon => unsafe { crate::simd::batch_neon::transform_vec4s_neon(m, src, dst) },
+        _ => transform_vec4s_scalar(m, src, dst),
+    }
+}
+
+pub fn transform_vec4s_in_place(m: &Matrix4x4, vectors: &mut [Vector4]) {
+    for chunk in vectors.chunks_mut(IN_PLACE_CHUNK) {
+        let mut src = [Vector4::zero(); IN_PLACE_CHUNK];
+        src[..chunk.len()].copy_from_slice(chunk);
+
+        transform_vec4s(m, &src[..chunk.len()], chunk);
+    }
+}
+
+// Elements copied aside at a time by the in-place variants.
+const IN_PLACE_CHUNK: usize = 64;
+
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Mode {
+    Point,
+    Direction,
+    PerspectiveDivide,
+}
+
+impl Mode {
+    // The w the Vector3 is extended with before the product.
+    #[inline(always)]
+    pub(crate) fn w(self) -> f32 {
+        if self == Mode::Direction {
+            0.
+        } else {
+            1.
+        }
+    }
+}
+
+fn transform_vector3s(m: &Matrix4x4, src: &[Vector3], dst: &mut [Vector3], mode: Mode) {
+    match active_simd_backend() {
+        #[cfg(target_arch = "x86_64")]
+        SimdBackend::Avx2Fma => unsafe { crate::simd::batch_x86::transform_vector3s_avx2_fma(m, src, dst, mode) },
+        #[cfg(target_arch = "x86_64")]
+        SimdBackend::Sse41 => unsafe { crate::simd::batch_x86::transform_vector3s_sse41(m, src, dst, mode) },
+        #[cfg(target_arch = "aarch64")]
+        SimdBackend::Neon => unsafe { crate::simd::batch_neon::transform_vector3s_neon(m, src, dst, mode) },
+        _ => transform_vector3s_scalar(m, src, dst, mode),
+    }
+}
+
+fn transform_vector3s_in_place(m: &Matrix4x4, vectors: &mut [Vector3], mode: Mode) {
+    for chunk in vectors.chunks_mut(IN_PLACE_CHUNK) {
+        let mut src = [Vector3::zero(); IN_PLACE_CHUNK];
+        src[..chunk.len()].copy_from_slice(chunk);
+
+        transform_vector3s(m, &src[..chunk.len()], chunk, mode);
+    }
+}
+
+fn transform_vec4s_scalar(m: &Matrix4x4, src: &[Vector4], dst: &mut [Vector4]) {
+    let mut src_chunks = src.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        let lanes = |f: fn(&Vector4) -> f32| Vector4::new(f(&s[0]), f(&s[1]), f(&s[2]), f(&s[3]));
+        let (x, y, z, w) = (lanes(|v| v.x), lanes(|v| v.y), lanes(|v| v.z), lanes(|v| v.w));
+
+        let row = |i: usize| m[0][i] * x + m[1][i] * y + m[2][i] * z + m[3][i] * w;
+        let (rx, ry, rz, rw) = (row(0), row(1), row(2), row(3));
+
+        d[0] = Vector4::new(rx.x, ry.x, rz.x, rw.x);
+        d[1] = Vector4::new(rx.y, ry.y, rz.y, rw.y);
+        d[2] = Vector4::new(rx.z, ry.z, rz.z, rw.z);
+        d[3] = Vector4::new(rx.w, ry.w, rz.w, rw.w);
+    }
+
+    for (s, d) in src_chunks.remainder().iter().zip(dst_chunks.into_remainder()) {
+        *d = *m * *s;
+    }
+}
+
+fn transform_vector3s_scalar(m: &Matrix4x4, src: &[Vector3], dst: &mut [Vector3], mode: Mode) {
+    let mut src_chunks = src.chunks_exact(4);
+    let mut dst_chunks = dst.chunks_exact_mut(4);
+
+    for (s, d) in (&mut src_chunks).zip(&mut dst_chunks) {
+        let lanes = |f: fn(&Vector3) -> f32| Vector4::new(f(&s[0]), f(&s[1]), f(&s[2]), f(&s[3]));
+        let (x, y, z) = (lanes(|v| v.x), lanes(|v| v.y), lanes(|v| v.z));
+
+        let row = |i: usize| m[0][i] * x + m[1][i] * y + m[2][i] * z + Vector4::all(m[3][i] * mode.w());
+        let (mut rx, mut ry, mut rz) = (row(0), row(1), row(2));
+
+        if mode == Mode::PerspectiveDivide {
+            let rw = row(3);
+            rx /= rw;
+            ry /= rw;
+            rz /= rw;
+        }
+
+        d[0] = Vector3::new(rx.x, ry.x, rz.x);
+        d[1] = Vector3::new(rx.y, ry.y, rz.y);
+        d[2] = Vector3::new(rx.z, ry.z, rz.z);
+        d[3] = Vector3::new(rx.w, ry.w, rz.w);
+    }
+
+    for (s, d) in src_chunks.remainder().iter().zip(dst_chunks.into_remainder()) {
+        *d = transform_vector3(m, *s, mode);
+    }
+}
+
+#[inline]
+fn transform_vector3(m: &Matrix4x4, v: Vector3, mode: Mode) -> Vector3 {
+    let r = match mode {
+        Mode::Direction => *m * v.to_vec4_as_direction(),
+        _ => *m * v.to_vec4_as_point(),
+    };
+
+    match mode {
+        Mode::PerspectiveDivide => Vector3::new(r.x / r.w, r.y / r.w, r.z / r.w),
+        _ => Vector3::new(r.x, r.y, r.z),
+    }
+}
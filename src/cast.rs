@@ -0,0 +1,40 @@
+use crate::f16;
+
+// Zero-copy views between slices of math types and slices of their scalars, e.g. to upload
+// a `&[Vector3]` to an API taking `&[f32]`, or to view loaded buffer data as vectors.
+
+// Types that are `#[repr(C)]` structs of `COUNT` values of `Scalar`, without padding and
+// with the alignment of `Scalar`. Sealed, since the casts below rely on that layout.
+pub trait Components: Copy + sealed::Sealed {
+    type Scalar: Copy;
+    const COUNT: usize;
+}
+
+#[inline]
+pub fn as_slice<T: Components>(values: &[T]) -> &[T::Scalar] {
+    unsafe { core::slice::from_raw_parts(values.as_ptr() as *const T::Scalar, values.len() * T::COUNT) }
+}
+
+#[inline]
+pub fn as_mut_slice<T: Components>(values: &mut [T]) -> &mut [T::Scalar] {
+    unsafe { core::slice::from_raw_parts_mut(values.as_mut_ptr() as *mut T::Scalar, values.len() * T::COUNT) }
+}
+
+// Panics if the length is not a multiple of `T::COUNT`.
+#[inline]
+pub fn from_slice<T: Components>(scalars: &[T::Scalar]) -> &[T] {
+    assert_eq!(scalars.len() % T::COUNT, 0, "slice length is not a multiple of {}", T::COUNT);
+    unsafe { core::slice::from_raw_parts(scalars.as_ptr() as *const T, scalars.len() / T::COUNT) }
+}
+
+#[inline]
+pub fn from_mut_slice<T: Components>(scalars: &mut [T::Scalar]) -> &mut [T] {
+    assert_eq!(scalars.len() % T::COUNT, 0, "slice length is not a multiple of {}", T::COUNT);
+    unsafe { core::slice::from_raw_parts_mut(scalars.as_mut_ptr() as *mut T, scalars.len() / T::COUNT) }
+}
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+include!(concat!(env!("OUT_DIR"), "/cast.gen.rs"));
@@ -0,0 +1,426 @@
+#![allow(clippy::excessive_precision)]
+
+use crate::{approx, Vector3, Vector4};
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use crate::math::FloatMath;
+use core::fmt::Debug;
+use core::ops::{Add, Mul, Sub};
+
+const SRGB_TO_XYZ: [Vector3; 3] = [
+    Vector3 { x: 0.4124564, y: 0.3575761, z: 0.1804375 },
+    Vector3 { x: 0.2126729, y: 0.7151522, z: 0.0721750 },
+    Vector3 { x: 0.0193339, y: 0.1191920, z: 0.9503041 },
+];
+
+const XYZ_TO_SRGB: [Vector3; 3] = [
+    Vector3 { x: 3.2404542, y: -1.5371385, z: -0.4985314 },
+    Vector3 { x: -0.9692660, y: 1.8760108, z: 0.0415560 },
+    Vector3 { x: 0.0556434, y: -0.2040259, z: 1.0572252 },
+];
+
+const SRGB_TO_LMS: [Vector3; 3] = [
+    Vector3 { x: 0.4122214708, y: 0.5363325363, z: 0.0514459929 },
+    Vector3 { x: 0.2119034982, y: 0.6806995451, z: 0.1073969566 },
+    Vector3 { x: 0.0883024619, y: 0.2817188376, z: 0.6299787005 },
+];
+
+const LMS_TO_OKLAB: [Vector3; 3] = [
+    Vector3 { x: 0.2104542553, y: 0.7936177850, z: -0.0040720468 },
+    Vector3 { x: 1.9779984951, y: -2.4285922050, z: 0.4505937099 },
+    Vector3 { x: 0.0259040371, y: 0.7827717662, z: -0.8086757660 },
+];
+
+const OKLAB_TO_LMS: [Vector3; 3] = [
+    Vector3 { x: 1., y: 0.3963377774, z: 0.2158037573 },
+    Vector3 { x: 1., y: -0.1055613458, z: -0.0638541728 },
+    Vector3 { x: 1., y: -0.0894841775, z: -1.2914855480 },
+];
+
+const LMS_TO_SRGB: [Vector3; 3] = [
+    Vector3 { x: 4.0767416621, y: -3.3077115913, z: 0.2309699292 },
+    Vector3 { x: -1.2684380046, y: 2.6097574011, z: -0.3413193965 },
+    Vector3 { x: -0.0041960863, y: -0.7034186147, z: 1.7076147010 },
+];
+
+const D65_WHITE: Vector3 = Vector3 { x: 0.95047, y: 1., z: 1.08883 };
+
+const LAB_DELTA: f32 = 6. / 29.;
+
+#[inline]
+fn mul_rows(rows: &[Vector3; 3], v: Vector3) -> Vector3 {
+    Vector3::new(
+        Vector3::dot(rows[0], v),
+        Vector3::dot(rows[1], v),
+        Vector3::dot(rows[2], v),
+    )
+}
+
+#[inline]
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+#[inline]
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1. / 2.4) - 0.055
+    }
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum BlendMode {
+    Normal,
+    Multiply,
+    Screen,
+    Overlay,
+    Darken,
+    Lighten,
+    Difference,
+    Additive,
+}
+
+impl BlendMode {
+    #[inline]
+    fn apply(self, cb: f32, cs: f32) -> f32 {
+        match self {
+            BlendMode::Normal => cs,
+            BlendMode::Multiply => cb * cs,
+            BlendMode::Screen => cb + cs - cb * cs,
+            BlendMode::Overlay => BlendMode::hard_light(cs, cb),
+            BlendMode::Darken => cb.min(cs),
+            BlendMode::Lighten => cb.max(cs),
+            BlendMode::Difference => (cb - cs).abs(),
+            BlendMode::Additive => cb + cs,
+        }
+    }
+
+    #[inline]
+    fn hard_light(cb: f32, cs: f32) -> f32 {
+        if cs <= 0.5 {
+            cb * 2. * cs
+        } else {
+            let s = 2. * cs - 1.;
+            cb + s - cb * s
+        }
+    }
+}
+
+#[repr(C)]
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+pub struct Color {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+    pub a: f32,
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color { r: 0., g: 0., b: 0., a: 0. };
+    pub const BLACK: Color = Color { r: 0., g: 0., b: 0., a: 1. };
+    pub const WHITE: Color = Color { r: 1., g: 1., b: 1., a: 1. };
+    pub const RED: Color = Color { r: 1., g: 0., b: 0., a: 1. };
+    pub const GREEN: Color = Color { r: 0., g: 1., b: 0., a: 1. };
+    pub const BLUE: Color = Color { r: 0., g: 0., b: 1., a: 1. };
+
+    #[inline]
+    pub const fn new(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color { r, g, b, a }
+    }
+
+    #[inline]
+    pub const fn rgb(r: f32, g: f32, b: f32) -> Color {
+        Color::new(r, g, b, 1.)
+    }
+
+    #[inline]
+    pub fn from_srgb(r: f32, g: f32, b: f32, a: f32) -> Color {
+        Color::new(srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a)
+    }
+
+    #[inline]
+    pub fn to_srgb(self) -> Vector4 {
+        Vector4::new(
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+            self.a,
+        )
+    }
+
+    #[inline]
+    pub fn rgb_vec(self) -> Vector3 {
+        Vector3::new(self.r, self.g, self.b)
+    }
+
+    #[inline]
+    fn from_rgb_vec(rgb: Vector3, a: f32) -> Color {
+        Color::new(rgb.x, rgb.y, rgb.z, a)
+    }
+
+    #[inline]
+    pub fn with_alpha(self, a: f32) -> Color {
+        Color::new(self.r, self.g, self.b, a)
+    }
+
+    #[inline]
+    pub fn luminance(self) -> f32 {
+        Vector3::dot(SRGB_TO_XYZ[1], self.rgb_vec())
+    }
+
+    // HSV and HSL operate on sRGB-encoded components, as color pickers expect.
+    // Hue is in degrees in [0, 360).
+    pub fn from_hsv(hsv: Vector3, a: f32) -> Color {
+        let (h, s, v) = (hsv.x, hsv.y, hsv.z);
+        let c = v * s;
+        let (r, g, b) = Color::hue_to_rgb(h, c);
+        let m = v - c;
+
+        Color::from_srgb(r + m, g + m, b + m, a)
+    }
+
+    pub fn to_hsv(self) -> Vector3 {
+        let srgb = self.to_srgb();
+        let max = srgb.x.max(srgb.y).max(srgb.z);
+        let min = srgb.x.min(srgb.y).min(srgb.z);
+        let c = max - min;
+
+        let s = if max > 0. { c / max } else { 0. };
+
+        Vector3::new(Color::hue(Vector3::new(srgb.x, srgb.y, srgb.z), max, c), s, max)
+    }
+
+    pub fn from_hsl(hsl: Vector3, a: f32) -> Color {
+        let (h, s, l) = (hsl.x, hsl.y, hsl.z);
+        let c = (1. - (2. * l - 1.).abs()) * s;
+        let (r, g, b) = Color::hue_to_rgb(h, c);
+        let m = l - c * 0.5;
+
+        Color::from_srgb(r + m, g + m, b + m, a)
+    }
+
+    pub fn to_hsl(self) -> Vector3 {
+        let srgb = self.to_srgb();
+        let max = srgb.x.max(srgb.y).max(srgb.z);
+        let min = srgb.x.min(srgb.y).min(srgb.z);
+        let c = max - min;
+        let l = (max + min) * 0.5;
+
+        let s = if c == 0. { 0. } else { c / (1. - (2. * l - 1.).abs()) };
+
+        Vector3::new(Color::hue(Vector3::new(srgb.x, srgb.y, srgb.z), max, c), s, l)
+    }
+
+    fn hue_to_rgb(h: f32, c: f32) -> (f32, f32, f32) {
+        let h = h.rem_euclid(360.) / 60.;
+        let x = c * (1. - (h % 2. - 1.).abs());
+
+        match h as u32 {
+            0 => (c, x, 0.),
+            1 => (x, c, 0.),
+            2 => (0., c, x),
+            3 => (0., x, c),
+            4 => (x, 0., c),
+            _ => (c, 0., x),
+        }
+    }
+
+    fn hue(rgb: Vector3, max: f32, c: f32) -> f32 {
+        if c == 0. {
+            return 0.;
+        }
+
+        let h = if max == rgb.x {
+            ((rgb.y - rgb.z) / c).rem_euclid(6.)
+        } else if max == rgb.y {
+            (rgb.z - rgb.x) / c + 2.
+        } else {
+            (rgb.x - rgb.y) / c + 4.
+        };
+
+        h * 60.
+    }
+
+    pub fn from_xyz(xyz: Vector3, a: f32) -> Color {
+        Color::from_rgb_vec(mul_rows(&XYZ_TO_SRGB, xyz), a)
+    }
+
+    #[inline]
+    pub fn to_xyz(self) -> Vector3 {
+        mul_rows(&SRGB_TO_XYZ, self.rgb_vec())
+    }
+
+    // CIE L*a*b* relative to the D65 white point.
+    pub fn from_lab(lab: Vector3, a: f32) -> Color {
+        let fy = (lab.x + 16.) / 116.;
+        let fx = fy + lab.y / 500.;
+        let fz = fy - lab.z / 200.;
+
+        let finv = |t: f32| {
+            if t > LAB_DELTA {
+                t * t * t
+            } else {
+                3. * LAB_DELTA * LAB_DELTA * (t - 4. / 29.)
+            }
+        };
+
+        let xyz = Vector3::new(finv(fx), finv(fy), finv(fz)) * D65_WHITE;
+        Color::from_xyz(xyz, a)
+    }
+
+    pub fn to_lab(self) -> Vector3 {
+        let xyz = self.to_xyz() / D65_WHITE;
+
+        let f = |t: f32| {
+            if t > LAB_DELTA * LAB_DELTA * LAB_DELTA {
+                t.cbrt()
+            } else {
+                t / (3. * LAB_DELTA * LAB_DELTA) + 4. / 29.
+            }
+        };
+
+        let (fx, fy, fz) = (f(xyz.x), f(xyz.y), f(xyz.z));
+        Vector3::new(116. * fy - 16., 500. * (fx - fy), 200. * (fy - fz))
+    }
+
+    pub fn from_oklab(lab: Vector3, a: f32) -> Color {
+        let lms = mul_rows(&OKLAB_TO_LMS, lab);
+        let lms = lms * lms * lms;
+
+        Color::from_rgb_vec(mul_rows(&LMS_TO_SRGB, lms), a)
+    }
+
+    pub fn to_oklab(self) -> Vector3 {
+        let lms = mul_rows(&SRGB_TO_LMS, self.rgb_vec());
+        let lms = Vector3::new(lms.x.cbrt(), lms.y.cbrt(), lms.z.cbrt());
+
+        mul_rows(&LMS_TO_OKLAB, lms)
+    }
+
+    // Oklch hue is in degrees in [0, 360).
+    pub fn from_oklch(lch: Vector3, a: f32) -> Color {
+        let h = lch.z.to_radians();
+        Color::from_oklab(Vector3::new(lch.x, lch.y * h.cos(), lch.y * h.sin()), a)
+    }
+
+    pub fn to_oklch(self) -> Vector3 {
+        let lab = self.to_oklab();
+        let c = (lab.y * lab.y + lab.z * lab.z).sqrt();
+        let h = lab.z.atan2(lab.y).to_degrees().rem_euclid(360.);
+
+        Vector3::new(lab.x, c, h)
+    }
+
+    #[inline]
+    pub fn premultiplied(self) -> Color {
+        Color::new(self.r * self.a, self.g * self.a, self.b * self.a, self.a)
+    }
+
+    #[inline]
+    pub fn unpremultiplied(self) -> Color {
+        if self.a == 0. {
+            return Color::TRANSPARENT;
+        }
+
+        let inv = 1. / self.a;
+        Color::new(self.r * inv, self.g * inv, self.b * inv, self.a)
+    }
+
+    // Composites straight-alpha `src` over `dst` using the separable blend modes
+    // from the W3C Compositing and Blending spec.
+    pub fn blend(src: Color, dst: Color, mode: BlendMode) -> Color {
+        let a_s = src.a;
+        let a_b = dst.a;
+        let a_o = a_s + a_b * (1. - a_s);
+
+        if a_o == 0. {
+            return Color::TRANSPARENT;
+        }
+
+        let channel = |cs: f32, cb: f32| {
+            let mixed = (1. - a_b) * cs + a_b * mode.apply(cb, cs);
+            (a_s * mixed + a_b * cb * (1. - a_s)) / a_o
+        };
+
+        Color::new(
+            channel(src.r, dst.r),
+            channel(src.g, dst.g),
+            channel(src.b, dst.b),
+            a_o,
+        )
+    }
+
+    #[inline]
+    pub fn approx(x: Color, y: Color) -> bool {
+        approx(x.r, y.r) && approx(x.g, y.g) && approx(x.b, y.b) && approx(x.a, y.a)
+    }
+}
+
+impl Add<Color> for Color {
+    type Output = Color;
+
+    #[inline]
+    fn add(self, rhs: Color) -> Color {
+        Color::new(self.r + rhs.r, self.g + rhs.g, self.b + rhs.b, self.a + rhs.a)
+    }
+}
+
+impl Sub<Color> for Color {
+    type Output = Color;
+
+    #[inline]
+    fn sub(self, rhs: Color) -> Color {
+        Color::new(self.r - rhs.r, self.g - rhs.g, self.b - rhs.b, self.a - rhs.a)
+    }
+}
+
+impl Mul<Color> for Color {
+    type Output = Color;
+
+    #[inline]
+    fn mul(self, rhs: Color) -> Color {
+        Color::new(self.r * rhs.r, self.g * rhs.g, self.b * rhs.b, self.a * rhs.a)
+    }
+}
+
+impl Mul<f32> for Color {
+    type Output = Color;
+
+    #[inline]
+    fn mul(self, rhs: f32) -> Color {
+        Color::new(self.r * rhs, self.g * rhs, self.b * rhs, self.a * rhs)
+    }
+}
+
+impl From<Vector4> for Color {
+    #[inline]
+    fn from(v: Vector4) -> Color {
+        Color::new(v.x, v.y, v.z, v.w)
+    }
+}
+
+impl From<Color> for Vector4 {
+    #[inline]
+    fn from(c: Color) -> Vector4 {
+        Vector4::new(c.r, c.g, c.b, c.a)
+    }
+}
+
+impl From<Vector3> for Color {
+    #[inline]
+    fn from(v: Vector3) -> Color {
+        Color::from_rgb_vec(v, 1.)
+    }
+}
+
+impl From<Color> for Vector3 {
+    #[inline]
+    fn from(c: Color) -> Vector3 {
+        c.rgb_vec()
+    }
+}
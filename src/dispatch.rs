@@ -0,0 +1,109 @@
+use core::sync::atomic::{AtomicU8, Ordering};
+
+// Implementations the batch APIs can pick at runtime, independently of the compile time
+// `enable_simd` choice. `Scalar` is the portable path, which still goes through the
+// Vector4 ops and so through whatever `enable_simd` backend was compiled in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SimdBackend {
+    Scalar,
+    Sse41,
+    // Rounds every multiply-add once, so results can differ from `Scalar` in the last bit.
+    Avx2Fma,
+    Neon,
+}
+
+// Runtime detection needs std. Without it only the features enabled at compile time count.
+#[cfg(all(feature = "std", any(target_arch = "x86_64", target_arch = "aarch64")))]
+macro_rules! has_cpu_features {
+    (x86, $($feature:tt),+) => {
+        $(std::arch::is_x86_feature_detected!($feature))&&+
+    };
+    (aarch64, $($feature:tt),+) => {
+        $(std::arch::is_aarch64_feature_detected!($feature))&&+
+    };
+}
+
+#[cfg(all(not(feature = "std"), any(target_arch = "x86_64", target_arch = "aarch64")))]
+macro_rules! has_cpu_features {
+    ($arch:ident, $($feature:tt),+) => {
+        cfg!(all($(target_feature = $feature),+))
+    };
+}
+
+impl SimdBackend {
+    pub const ALL: [SimdBackend; 4] = [
+        SimdBackend::Scalar,
+        SimdBackend::Sse41,
+        SimdBackend::Avx2Fma,
+        SimdBackend::Neon,
+    ];
+
+    pub fn is_supported(self) -> bool {
+        match self {
+            SimdBackend::Scalar => true,
+            #[cfg(target_arch = "x86_64")]
+            SimdBackend::Sse41 => has_cpu_features!(x86, "sse4.1"),
+            #[cfg(target_arch = "x86_64")]
+            SimdBackend::Avx2Fma => has_cpu_features!(x86, "avx2", "fma"),
+            #[cfg(target_arch = "aarch64")]
+            SimdBackend::Neon => has_cpu_features!(aarch64, "neon"),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    fn to_bits(self) -> u8 {
+        self as u8 + 1
+    }
+
+    fn from_bits(bits: u8) -> Option<SimdBackend> {
+        SimdBackend::ALL
+            .get((bits as usize).checked_sub(1)?)
+            .copied()
+    }
+}
+
+static DETECTED_BACKEND: AtomicU8 = AtomicU8::new(0);
+static FORCED_BACKEND: AtomicU8 = AtomicU8::new(0);
+
+// The backend the batch APIs currently dispatch to.
+pub fn active_simd_backend() -> SimdBackend {
+    if let Some(forced) = SimdBackend::from_bits(FORCED_BACKEND.load(Ordering::Relaxed)) {
+        return forced;
+    }
+
+    if let Some(detected) = SimdBackend::from_bits(DETECTED_BACKEND.load(Ordering::Relaxed)) {
+        return detected;
+    }
+
+    let detected = detect_backend();
+    DETECTED_BACKEND.store(detected.to_bits(), Ordering::Relaxed);
+
+    detected
+}
+
+// Overrides runtime detection for the whole process, e.g. `Some(SimdBackend::Scalar)` to
+// compare against the portable path in tests. `None` goes back to the detected backend.
+pub fn force_simd_backend(backend: Option<SimdBackend>) {
+    if let Some(backend) = backend {
+        assert!(
+            backend.is_supported(),
+            "{backend:?} is not supported by this CPU"
+        );
+    }
+
+    FORCED_BACKEND.store(backend.map_or(0, SimdBackend::to_bits), Ordering::Relaxed);
+}
+
+fn detect_backend() -> SimdBackend {
+    [SimdBackend::Avx2Fma, SimdBackend::Sse41, SimdBackend::Neon]
+        .into_iter()
+        .find(|b| b.is_supported())
+        .unwrap_or(SimdBackend::Scalar)
+}
+
+// F16C for the half slice conversions, detected the same way as the batch backends.
+#[cfg(target_arch = "x86_64")]
+pub(crate) fn has_f16c() -> bool {
+    has_cpu_features!(x86, "f16c")
+}
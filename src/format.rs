@@ -0,0 +1,152 @@
+use core::fmt::{Display, Formatter, Write};
+use core::str::FromStr;
+
+// Text formats shared by every vector, `Color` and the matrices:
+//   vectors:  `[1, 2, 3]`
+//   matrices: `[[m00, m01, m02, m03], [m10, ...], ...]`, one inner list per row.
+// `{:#}` prints a matrix as an aligned grid with one row per line, which parses back the same.
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum ParseMathError {
+    // Missing `[`/`]`, or matrix rows not separated by commas.
+    InvalidFormat,
+    // Wrong number of components, or of rows and row components for a matrix.
+    WrongLength { expected: usize, found: usize },
+    // The component couldn't be parsed as the scalar type. Matrix indices go row by row.
+    InvalidComponent { index: usize },
+}
+
+impl Display for ParseMathError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseMathError::InvalidFormat => f.write_str("expected a list wrapped in `[` and `]`"),
+            ParseMathError::WrongLength { expected, found } => {
+                write!(f, "expected {expected} components, found {found}")
+            }
+            ParseMathError::InvalidComponent { index } => write!(f, "invalid component at index {index}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseMathError {}
+
+// Each component goes through the caller's formatter, so precision, width and sign flags
+// apply per component.
+pub(crate) fn fmt_components<T: Display>(f: &mut Formatter<'_>, components: &[T]) -> core::fmt::Result {
+    f.write_char('[')?;
+
+    for (i, c) in components.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+        Display::fmt(c, f)?;
+    }
+
+    f.write_char(']')
+}
+
+pub(crate) fn fmt_rows<T: Display, const N: usize>(f: &mut Formatter<'_>, rows: &[[T; N]; N]) -> core::fmt::Result {
+    if !f.alternate() {
+        f.write_char('[')?;
+        for (i, row) in rows.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            fmt_components(f, row)?;
+        }
+        return f.write_char(']');
+    }
+
+    // Right aligns every column to its widest value.
+    let precision = f.precision();
+    let mut widths = [0; N];
+    for row in rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            let mut counter = CharCounter(0);
+            write_padded(&mut counter, value, 0, precision)?;
+            *width = (*width).max(counter.0);
+        }
+    }
+
+    for (r, row) in rows.iter().enumerate() {
+        f.write_str(if r == 0 { "[[" } else { " [" })?;
+        for (c, value) in row.iter().enumerate() {
+            if c > 0 {
+                f.write_str(", ")?;
+            }
+            write_padded(f, value, widths[c], precision)?;
+        }
+        f.write_str(if r == N - 1 { "]]" } else { "],\n" })?;
+    }
+
+    Ok(())
+}
+
+fn write_padded<W: Write, T: Display>(w: &mut W, value: &T, width: usize, precision: Option<usize>) -> core::fmt::Result {
+    match precision {
+        Some(precision) => write!(w, "{value:>width$.precision$}"),
+        None => write!(w, "{value:>width$}"),
+    }
+}
+
+struct CharCounter(usize);
+
+impl Write for CharCounter {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+fn strip_brackets(s: &str) -> Result<&str, ParseMathError> {
+    s.trim()
+        .strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .ok_or(ParseMathError::InvalidFormat)
+}
+
+pub(crate) fn parse_components<T: FromStr + Copy + Default, const N: usize>(s: &str) -> Result<[T; N], ParseMathError> {
+    let list = strip_brackets(s)?;
+    let found = list.split(',').count();
+    if found != N {
+        return Err(ParseMathError::WrongLength { expected: N, found });
+    }
+
+    let mut components = [T::default(); N];
+    for (index, (c, part)) in components.iter_mut().zip(list.split(',')).enumerate() {
+        *c = part
+            .trim()
+            .parse()
+            .map_err(|_| ParseMathError::InvalidComponent { index })?;
+    }
+
+    Ok(components)
+}
+
+pub(crate) fn parse_rows<T: FromStr + Copy + Default, const N: usize>(s: &str) -> Result<[[T; N]; N], ParseMathError> {
+    let mut rest = strip_brackets(s)?.trim();
+    let found = rest.matches('[').count();
+    if found != N {
+        return Err(ParseMathError::WrongLength { expected: N, found });
+    }
+
+    let mut rows = [[T::default(); N]; N];
+    for (r, row) in rows.iter_mut().enumerate() {
+        if r > 0 {
+            rest = rest.strip_prefix(',').ok_or(ParseMathError::InvalidFormat)?.trim_start();
+        }
+
+        let end = rest.find(']').ok_or(ParseMathError::InvalidFormat)? + 1;
+        *row = parse_components(&rest[..end]).map_err(|e| match e {
+            ParseMathError::InvalidComponent { index } => ParseMathError::InvalidComponent { index: r * N + index },
+            e => e,
+        })?;
+        rest = rest[end..].trim_start();
+    }
+
+    if rest.is_empty() {
+        Ok(rows)
+    } else {
+        Err(ParseMathError::InvalidFormat)
+    }
+}
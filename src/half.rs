@@ -0,0 +1,172 @@
+use crate::cast;
+use crate::packing::round_shift_even;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use crate::math::FloatMath;
+use crate::{Vector2, Vector3, Vector4};
+use core::cmp::Ordering;
+use core::fmt::{Debug, Display, Formatter};
+use core::num::ParseFloatError;
+use core::str::FromStr;
+
+#[allow(non_camel_case_types)]
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct f16(u16);
+
+impl f16 {
+    pub const ZERO: f16 = f16(0x0000);
+    pub const ONE: f16 = f16(0x3c00);
+    pub const NEG_ONE: f16 = f16(0xbc00);
+    pub const MAX: f16 = f16(0x7bff);
+    pub const MIN: f16 = f16(0xfbff);
+    pub const MIN_POSITIVE: f16 = f16(0x0400);
+    pub const EPSILON: f16 = f16(0x1400);
+    pub const INFINITY: f16 = f16(0x7c00);
+    pub const NEG_INFINITY: f16 = f16(0xfc00);
+    pub const NAN: f16 = f16(0x7e00);
+
+    #[inline]
+    pub const fn from_bits(bits: u16) -> f16 {
+        f16(bits)
+    }
+
+    #[inline]
+    pub const fn to_bits(self) -> u16 {
+        self.0
+    }
+
+    // Rounds to nearest, ties to even, like the F16C and NEON conversions.
+    pub fn from_f32(v: f32) -> f16 {
+        let bits = v.to_bits();
+        let sign = ((bits >> 16) & 0x8000) as u16;
+        let exp = ((bits >> 23) & 0xff) as i32;
+        let mantissa = bits & 0x7f_ffff;
+
+        if exp == 0xff {
+            let nan_bits = if mantissa != 0 { 0x0200 | (mantissa >> 13) as u16 } else { 0 };
+            return f16(sign | 0x7c00 | nan_bits);
+        }
+
+        let half_exp = exp - 127 + 15;
+
+        let magnitude = if half_exp > 0 {
+            round_shift_even((half_exp as u32) << 23 | mantissa, 13).min(0x7c00)
+        } else if half_exp >= -10 {
+            round_shift_even(mantissa | 0x80_0000, (14 - half_exp) as u32)
+        } else {
+            0
+        };
+
+        f16(sign | magnitude as u16)
+    }
+
+    pub fn to_f32(self) -> f32 {
+        let h = self.0 as u32;
+        let sign = (h & 0x8000) << 16;
+        let exp = (h >> 10) & 0x1f;
+        let mantissa = h & 0x3ff;
+
+        let bits = match exp {
+            0 => (mantissa as f32 * 2f32.powi(-24)).to_bits(),
+            // NaNs come out quiet, as with F16C and FCVTL.
+            0x1f if mantissa != 0 => 0x7fc0_0000 | mantissa << 13,
+            0x1f => 0x7f80_0000,
+            _ => (exp + 112) << 23 | mantissa << 13,
+        };
+
+        f32::from_bits(sign | bits)
+    }
+
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.0 & 0x7fff > 0x7c00
+    }
+
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.0 & 0x7fff == 0x7c00
+    }
+
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.0 & 0x7c00 != 0x7c00
+    }
+}
+
+impl From<f16> for f32 {
+    #[inline]
+    fn from(v: f16) -> f32 {
+        v.to_f32()
+    }
+}
+
+impl From<f32> for f16 {
+    #[inline]
+    fn from(v: f32) -> f16 {
+        f16::from_f32(v)
+    }
+}
+
+impl PartialEq for f16 {
+    #[inline]
+    fn eq(&self, other: &f16) -> bool {
+        self.to_f32() == other.to_f32()
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &f16) -> Option<Ordering> {
+        self.to_f32().partial_cmp(&other.to_f32())
+    }
+}
+
+impl Debug for f16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Debug::fmt(&self.to_f32(), f)
+    }
+}
+
+impl Display for f16 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        Display::fmt(&self.to_f32(), f)
+    }
+}
+
+impl Default for f16 {
+    #[inline]
+    fn default() -> f16 {
+        f16::ZERO
+    }
+}
+
+// Parses as f32 and rounds, like `f16::from_f32`.
+impl FromStr for f16 {
+    type Err = ParseFloatError;
+
+    fn from_str(s: &str) -> Result<f16, ParseFloatError> {
+        s.parse().map(f16::from_f32)
+    }
+}
+
+pub fn f32_to_f16_slice(src: &[f32], dst: &mut [f16]) {
+    assert_eq!(src.len(), dst.len());
+
+    let simd_len = crate::simd::f16_convert::f32_to_f16_simd(src, dst);
+
+    for (s, d) in src[simd_len..].iter().zip(dst[simd_len..].iter_mut()) {
+        *d = f16::from_f32(*s);
+    }
+}
+
+pub fn f16_to_f32_slice(src: &[f16], dst: &mut [f32]) {
+    assert_eq!(src.len(), dst.len());
+
+    let simd_len = crate::simd::f16_convert::f16_to_f32_simd(src, dst);
+
+    for (s, d) in src[simd_len..].iter().zip(dst[simd_len..].iter_mut()) {
+        *d = s.to_f32();
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/half_vector.gen.rs"));
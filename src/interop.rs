@@ -0,0 +1,6 @@
+use crate::*;
+
+// `From` conversions to and from the equivalent mint, glam and nalgebra types, each behind
+// the feature of the same name.
+
+include!(concat!(env!("OUT_DIR"), "/interop.gen.rs"));
@@ -0,0 +1,314 @@
+use crate::*;
+
+// GLSL std140/std430 buffer layouts (OpenGL 4.6 spec, section 7.6.2.2). Describe a block by
+// implementing `GpuStruct`, then let `LayoutRules::write` place every member at its aligned
+// offset in a byte buffer, e.g.
+//
+//     impl GpuStruct for Light {
+//         fn write_fields(&self, w: &mut LayoutWriter<'_>) {
+//             w.field(self.position);
+//             w.field(self.intensity);
+//             w.array(&self.falloff);
+//         }
+//     }
+//
+//     let size = LayoutRules::Std140.size_of(&light);
+//     LayoutRules::Std140.write(&light, &mut buffer[..size]);
+//
+// Values are written little-endian, padding bytes are zeroed.
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum LayoutRules {
+    // Uniform blocks. Array elements and structs are aligned to 16 bytes.
+    Std140,
+    // Shader storage blocks. Arrays and structs keep the alignment of their members.
+    Std430,
+}
+
+impl LayoutRules {
+    // Size of the block `value` describes, including the padding at its end.
+    pub fn size_of<S: GpuStruct>(self, value: &S) -> usize {
+        let mut w = LayoutWriter::measure(self);
+        value.write_fields(&mut w);
+        w.size()
+    }
+
+    // Returns the number of bytes written, panics if the buffer is too small.
+    pub fn write<S: GpuStruct>(self, value: &S, buffer: &mut [u8]) -> usize {
+        let mut w = LayoutWriter::new(self, buffer);
+        value.write_fields(&mut w);
+        w.finish()
+    }
+
+    fn array_stride(self, align: usize, size: usize) -> (usize, usize) {
+        let align = self.aggregate_align(align);
+        (align, size.next_multiple_of(align))
+    }
+
+    // Alignment of an array or struct whose largest member alignment is `align`.
+    fn aggregate_align(self, align: usize) -> usize {
+        match self {
+            LayoutRules::Std140 => align.max(16),
+            LayoutRules::Std430 => align,
+        }
+    }
+}
+
+// Types that can be a block member. `ALIGN` is the base alignment, `SIZE` the bytes
+// `write_bytes` fills, without any trailing padding.
+pub trait GpuType: Copy + sealed::Sealed {
+    const ALIGN: usize;
+    const SIZE: usize;
+
+    fn write_bytes(self, out: &mut [u8]);
+}
+
+// A block or a struct inside one, the order of the `LayoutWriter` calls is the member order.
+pub trait GpuStruct {
+    fn write_fields(&self, w: &mut LayoutWriter<'_>);
+}
+
+// Places members one after the other. Every call returns the offset of the member relative
+// to the start of the struct being written.
+pub struct LayoutWriter<'a> {
+    rules: LayoutRules,
+    // `None` only computes offsets.
+    buffer: Option<&'a mut [u8]>,
+    offset: usize,
+    align: usize,
+}
+
+impl<'a> LayoutWriter<'a> {
+    pub fn new(rules: LayoutRules, buffer: &'a mut [u8]) -> LayoutWriter<'a> {
+        LayoutWriter {
+            rules,
+            buffer: Some(buffer),
+            offset: 0,
+            align: 1,
+        }
+    }
+
+    pub fn measure(rules: LayoutRules) -> LayoutWriter<'a> {
+        LayoutWriter {
+            rules,
+            buffer: None,
+            offset: 0,
+            align: 1,
+        }
+    }
+
+    pub fn rules(&self) -> LayoutRules {
+        self.rules
+    }
+
+    // End of the last member.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    // Size of the struct so far, rounded up to its alignment.
+    pub fn size(&self) -> usize {
+        self.offset.next_multiple_of(self.rules.aggregate_align(self.align))
+    }
+
+    pub fn field<T: GpuType>(&mut self, value: T) -> usize {
+        let start = self.reserve(T::ALIGN, T::SIZE);
+        if let Some(buffer) = &mut self.buffer {
+            value.write_bytes(&mut buffer[start..start + T::SIZE]);
+        }
+        start
+    }
+
+    pub fn array<T: GpuType>(&mut self, values: &[T]) -> usize {
+        let (align, stride) = self.rules.array_stride(T::ALIGN, T::SIZE);
+        let start = self.reserve(align, stride * values.len());
+
+        if let Some(buffer) = &mut self.buffer {
+            let elements = buffer[start..start + stride * values.len()].chunks_exact_mut(stride);
+            for (value, element) in values.iter().zip(elements) {
+                value.write_bytes(&mut element[..T::SIZE]);
+                element[T::SIZE..].fill(0);
+            }
+        }
+
+        start
+    }
+
+    pub fn nested<S: GpuStruct>(&mut self, value: &S) -> usize {
+        self.struct_array(core::slice::from_ref(value))
+    }
+
+    pub fn struct_array<S: GpuStruct>(&mut self, values: &[S]) -> usize {
+        let Some(first) = values.first() else {
+            return self.offset;
+        };
+
+        // Alignment and size only depend on the types, so the first element stands for all.
+        let mut layout = LayoutWriter::measure(self.rules);
+        first.write_fields(&mut layout);
+        let align = self.rules.aggregate_align(layout.align);
+        let stride = layout.size();
+        let start = self.reserve(align, stride * values.len());
+
+        for (i, value) in values.iter().enumerate() {
+            let element_start = start + i * stride;
+            let mut element = LayoutWriter {
+                rules: self.rules,
+                buffer: self
+                    .buffer
+                    .as_deref_mut()
+                    .map(|b| &mut b[element_start..element_start + stride]),
+                offset: 0,
+                align: 1,
+            };
+            value.write_fields(&mut element);
+            element.finish();
+        }
+
+        start
+    }
+
+    // Zeroes the padding at the end of the struct and returns its size.
+    pub fn finish(mut self) -> usize {
+        let size = self.size();
+        self.zero_fill(size);
+        size
+    }
+
+    fn reserve(&mut self, align: usize, size: usize) -> usize {
+        let start = self.offset.next_multiple_of(align);
+        self.zero_fill(start);
+        self.offset = start + size;
+        self.align = self.align.max(align);
+        start
+    }
+
+    fn zero_fill(&mut self, end: usize) {
+        if let Some(buffer) = &mut self.buffer {
+            buffer[self.offset..end].fill(0);
+        }
+    }
+}
+
+// Pads `T` to a 16 byte slot, which is how std140 lays out array elements and how a `vec3`
+// followed by another `vec3` ends up. Lets `#[repr(C)]` structs mirror such blocks directly.
+#[repr(C, align(16))]
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+pub struct Align16<T>(pub T);
+
+// Same for double precision `dvec3`/`dvec4` array elements.
+#[repr(C, align(32))]
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
+pub struct Align32<T>(pub T);
+
+macro_rules! impl_aligned_wrapper {
+    ($wrapper:ident, $align:literal) => {
+        impl<T> From<T> for $wrapper<T> {
+            #[inline]
+            fn from(value: T) -> $wrapper<T> {
+                $wrapper(value)
+            }
+        }
+
+        impl<T: GpuType> sealed::Sealed for $wrapper<T> {}
+
+        impl<T: GpuType> GpuType for $wrapper<T> {
+            const ALIGN: usize = if T::ALIGN > $align { T::ALIGN } else { $align };
+            const SIZE: usize = T::SIZE.next_multiple_of(Self::ALIGN);
+
+            #[inline]
+            fn write_bytes(self, out: &mut [u8]) {
+                self.0.write_bytes(&mut out[..T::SIZE]);
+                out[T::SIZE..].fill(0);
+            }
+        }
+    };
+}
+
+impl_aligned_wrapper!(Align16, 16);
+impl_aligned_wrapper!(Align32, 32);
+
+macro_rules! impl_gpu_scalar {
+    ($($t:ty),*) => {$(
+        impl sealed::Sealed for $t {}
+
+        impl GpuType for $t {
+            const ALIGN: usize = core::mem::size_of::<$t>();
+            const SIZE: usize = core::mem::size_of::<$t>();
+
+            #[inline]
+            fn write_bytes(self, out: &mut [u8]) {
+                out.copy_from_slice(&self.to_le_bytes());
+            }
+        }
+    )*};
+}
+
+impl_gpu_scalar!(f32, f64, i32, u32);
+
+// GLSL booleans are 32 bit.
+impl sealed::Sealed for bool {}
+
+impl GpuType for bool {
+    const ALIGN: usize = 4;
+    const SIZE: usize = 4;
+
+    #[inline]
+    fn write_bytes(self, out: &mut [u8]) {
+        (self as u32).write_bytes(out);
+    }
+}
+
+// A vec3 is aligned like a vec4 but only takes 3 components, a following scalar fills the gap.
+macro_rules! impl_gpu_vector {
+    ($($ty:ty, $scalar:ty, $dim:literal);* $(;)?) => {$(
+        impl sealed::Sealed for $ty {}
+
+        impl GpuType for $ty {
+            const ALIGN: usize = <$scalar as GpuType>::ALIGN * if $dim == 2 { 2 } else { 4 };
+            const SIZE: usize = <$scalar as GpuType>::SIZE * $dim;
+
+            #[inline]
+            fn write_bytes(self, out: &mut [u8]) {
+                for (c, bytes) in self.to_array().into_iter().zip(out.chunks_exact_mut(<$scalar as GpuType>::SIZE)) {
+                    c.write_bytes(bytes);
+                }
+            }
+        }
+    )*};
+}
+
+impl_gpu_vector!(
+    Vector2, f32, 2; Vector3, f32, 3; Vector4, f32, 4;
+    DVector2, f64, 2; DVector3, f64, 3; DVector4, f64, 4;
+    IVector2, i32, 2; IVector3, i32, 3; IVector4, i32, 4;
+    UVector2, u32, 2; UVector3, u32, 3; UVector4, u32, 4;
+    BVector2, bool, 2; BVector3, bool, 3; BVector4, bool, 4;
+    Vector3A, f32, 3; Color, f32, 4;
+);
+
+// Column-major, stored like an array of 4 column vectors. The columns are vec4s, so the
+// array stride is the same under both rules.
+macro_rules! impl_gpu_matrix {
+    ($($mat:ty, $vec4:ty);*) => {$(
+        impl sealed::Sealed for $mat {}
+
+        impl GpuType for $mat {
+            const ALIGN: usize = <$vec4 as GpuType>::ALIGN;
+            const SIZE: usize = <$vec4 as GpuType>::SIZE * 4;
+
+            #[inline]
+            fn write_bytes(self, out: &mut [u8]) {
+                for (c, bytes) in out.chunks_exact_mut(<$vec4 as GpuType>::SIZE).enumerate() {
+                    self[c].write_bytes(bytes);
+                }
+            }
+        }
+    )*};
+}
+
+impl_gpu_matrix!(Matrix4x4, Vector4; DMatrix4x4, DVector4);
+
+mod sealed {
+    pub trait Sealed {}
+}
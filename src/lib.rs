@@ -1,16 +1,44 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+#![cfg_attr(feature = "portable_simd", feature(portable_simd))]
+
 mod math;
 mod vector2;
 mod vector3;
+mod vector3a;
 mod vector4;
 mod matrix4x4;
+mod color;
+mod packing;
+mod normal_encoding;
+mod half;
+mod batch;
+mod dispatch;
+mod wide;
+mod swizzle;
+mod format;
+#[cfg(any(feature = "mint", feature = "glam", feature = "nalgebra"))]
+mod interop;
+#[cfg(feature = "serde")]
+mod serialization;
 
 
 pub use vector2::*;
 pub use vector3::*;
+pub use vector3a::*;
 pub use vector4::*;
 pub use matrix4x4::*;
+pub use color::*;
+pub use packing::*;
+pub use normal_encoding::*;
+pub use half::*;
+pub use batch::*;
+pub use dispatch::*;
+pub use wide::*;
+pub use format::*;
+#[cfg(feature = "serde")]
+pub use serialization::*;
 
 pub(crate) mod simd;
+pub mod cast;
+pub mod layout;
 pub use math::*;
-
-include!(concat!(env!("OUT_DIR"), "/vector.swizzling.gen.rs"));
\ No newline at end of file
@@ -1,8 +1,9 @@
-use std::ops::{Add, Mul, Sub, Div};
+use core::ops::{Add, Mul, Sub, Div};
 
-use crate::{Matrix4x4, Vector2, Vector3, Vector4};
+use crate::{Color, Vector2, Vector3, Vector4};
 
 pub const EPSILON_SQR: f32 = (f32::EPSILON as f64 * f32::EPSILON as f64) as f32;
+pub const EPSILON_SQR_F64: f64 = f64::EPSILON * f64::EPSILON;
 
 pub fn lerp<TV, TL>(p0: TV, p1: TV, t: TL) -> TV
 where
@@ -21,13 +22,117 @@ pub fn approx(x: f32, y: f32) -> bool {
     d * d <= EPSILON_SQR
 }
 
-include!(concat!(env!("OUT_DIR"), "/vector.math.gen.rs"));
+pub fn approx_f64(x: f64, y: f64) -> bool {
+    let d = y - x;
+    d * d <= EPSILON_SQR_F64
+}
+
+#[derive(PartialEq, Eq, Copy, Clone, Debug)]
+pub enum RoundingMode {
+    Floor,
+    Ceil,
+    Round,
+    Truncate,
+}
+
+impl RoundingMode {
+    #[inline]
+    pub fn round_f32(self, v: f32) -> f32 {
+        match self {
+            RoundingMode::Floor => v.floor(),
+            RoundingMode::Ceil => v.ceil(),
+            RoundingMode::Round => v.round(),
+            RoundingMode::Truncate => v.trunc(),
+        }
+    }
 
-impl Matrix4x4 {
-    pub fn approx(x: Matrix4x4, y: Matrix4x4) -> bool {
-        Vector4::approx(x[0], y[0])
-            && Vector4::approx(x[1], y[1])
-            && Vector4::approx(x[2], y[2])
-            && Vector4::approx(x[3], y[3])
+    #[inline]
+    pub fn round_f64(self, v: f64) -> f64 {
+        match self {
+            RoundingMode::Floor => v.floor(),
+            RoundingMode::Ceil => v.ceil(),
+            RoundingMode::Round => v.round(),
+            RoundingMode::Truncate => v.trunc(),
+        }
     }
 }
+
+// The float functions std provides as inherent methods. Without std the `libm` feature is
+// required (build.rs stops otherwise) and the trait is imported wherever they are called,
+// so the same `x.sqrt()` calls resolve to libm instead; abs,
+// clamp, min, max and the sign functions come from core either way.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+pub(crate) trait FloatMath: Sized {
+    fn sqrt(self) -> Self;
+    fn cbrt(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn fract(self) -> Self;
+    fn rem_euclid(self, rhs: Self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+    fn powf(self, n: Self) -> Self;
+    fn powi(self, n: i32) -> Self;
+    fn exp(self) -> Self;
+    fn ln(self) -> Self;
+    fn sin(self) -> Self;
+    fn cos(self) -> Self;
+    fn sin_cos(self) -> (Self, Self);
+    fn acos(self) -> Self;
+    fn atan2(self, other: Self) -> Self;
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+macro_rules! impl_float_math {
+    ($t:ty) => {
+        impl FloatMath for $t {
+            #[inline]
+            fn sqrt(self) -> $t { libm::Libm::<$t>::sqrt(self) }
+            #[inline]
+            fn cbrt(self) -> $t { libm::Libm::<$t>::cbrt(self) }
+            #[inline]
+            fn floor(self) -> $t { libm::Libm::<$t>::floor(self) }
+            #[inline]
+            fn ceil(self) -> $t { libm::Libm::<$t>::ceil(self) }
+            #[inline]
+            fn round(self) -> $t { libm::Libm::<$t>::round(self) }
+            #[inline]
+            fn trunc(self) -> $t { libm::Libm::<$t>::trunc(self) }
+            #[inline]
+            fn fract(self) -> $t { self - self.trunc() }
+            #[inline]
+            fn rem_euclid(self, rhs: $t) -> $t {
+                let r = self % rhs;
+                if r < 0. { r + rhs.abs() } else { r }
+            }
+            #[inline]
+            fn mul_add(self, a: $t, b: $t) -> $t { libm::Libm::<$t>::fma(self, a, b) }
+            #[inline]
+            fn powf(self, n: $t) -> $t { libm::Libm::<$t>::pow(self, n) }
+            #[inline]
+            fn powi(self, n: i32) -> $t { libm::Libm::<$t>::pow(self, n as $t) }
+            #[inline]
+            fn exp(self) -> $t { libm::Libm::<$t>::exp(self) }
+            #[inline]
+            fn ln(self) -> $t { libm::Libm::<$t>::log(self) }
+            #[inline]
+            fn sin(self) -> $t { libm::Libm::<$t>::sin(self) }
+            #[inline]
+            fn cos(self) -> $t { libm::Libm::<$t>::cos(self) }
+            #[inline]
+            fn sin_cos(self) -> ($t, $t) { libm::Libm::<$t>::sincos(self) }
+            #[inline]
+            fn acos(self) -> $t { libm::Libm::<$t>::acos(self) }
+            #[inline]
+            fn atan2(self, other: $t) -> $t { libm::Libm::<$t>::atan2(self, other) }
+        }
+    };
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_float_math!(f32);
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_float_math!(f64);
+
+include!(concat!(env!("OUT_DIR"), "/vector.math.gen.rs"));
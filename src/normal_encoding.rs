@@ -0,0 +1,121 @@
+use crate::{Vector2, Vector3};
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use crate::math::FloatMath;
+use core::f32::consts::PI;
+
+#[inline]
+fn sign_not_zero(v: f32) -> f32 {
+    if v >= 0. {
+        1.
+    } else {
+        -1.
+    }
+}
+
+#[inline]
+fn oct_wrap(v: Vector2) -> Vector2 {
+    Vector2::new(
+        (1. - v.y.abs()) * sign_not_zero(v.x),
+        (1. - v.x.abs()) * sign_not_zero(v.y),
+    )
+}
+
+// Maps a unit vector onto the [-1, 1] square of an octahedron unfolded along +Z.
+pub fn oct_encode(n: Vector3) -> Vector2 {
+    let p = Vector2::new(n.x, n.y) / (n.x.abs() + n.y.abs() + n.z.abs());
+
+    if n.z >= 0. {
+        p
+    } else {
+        oct_wrap(p)
+    }
+}
+
+pub fn oct_decode(e: Vector2) -> Vector3 {
+    let z = 1. - e.x.abs() - e.y.abs();
+    let xy = if z < 0. { oct_wrap(e) } else { e };
+
+    Vector3::new(xy.x, xy.y, z).normalized()
+}
+
+#[inline]
+pub fn oct_encode_snorm16(n: Vector3) -> [i16; 2] {
+    oct_encode(n).to_snorm16()
+}
+
+#[inline]
+pub fn oct_decode_snorm16(e: [i16; 2]) -> Vector3 {
+    oct_decode(Vector2::from_snorm16(e))
+}
+
+#[inline]
+pub fn oct_encode_snorm8(n: Vector3) -> [i8; 2] {
+    oct_encode(n).to_snorm8()
+}
+
+#[inline]
+pub fn oct_decode_snorm8(e: [i8; 2]) -> Vector3 {
+    oct_decode(Vector2::from_snorm8(e))
+}
+
+// Searches the four quantized neighbours of the octahedral projection and keeps the
+// one that decodes closest to `n` (Cigolle et al. 2014).
+fn oct_encode_precise(n: Vector3, max: f32) -> Vector2 {
+    let base = (oct_encode(n) * max).floor();
+    let mut best = base;
+    let mut best_error = f32::MAX;
+
+    for (dx, dy) in [(0., 0.), (1., 0.), (0., 1.), (1., 1.)] {
+        let candidate = base + Vector2::new(dx, dy);
+        let decoded = oct_decode((candidate / max).clamp(Vector2::all(-1.), Vector2::one()));
+        let error = (decoded - n).length_sqr();
+
+        if error < best_error {
+            best_error = error;
+            best = candidate;
+        }
+    }
+
+    best / max
+}
+
+#[inline]
+pub fn oct_encode_precise_snorm16(n: Vector3) -> [i16; 2] {
+    oct_encode_precise(n, i16::MAX as f32).to_snorm16()
+}
+
+#[inline]
+pub fn oct_encode_precise_snorm8(n: Vector3) -> [i8; 2] {
+    oct_encode_precise(n, i8::MAX as f32).to_snorm8()
+}
+
+impl Vector3 {
+    // Returns (radius, polar angle from +Y, azimuth from +Z towards +X).
+    pub fn to_spherical(self) -> Vector3 {
+        let r = self.length();
+
+        if r == 0. {
+            return Vector3::zero();
+        }
+
+        Vector3::new(r, (self.y / r).clamp(-1., 1.).acos(), self.x.atan2(self.z))
+    }
+
+    pub fn from_spherical(spherical: Vector3) -> Vector3 {
+        let (r, theta, phi) = (spherical.x, spherical.y, spherical.z);
+        let (sin_theta, cos_theta) = theta.sin_cos();
+        let (sin_phi, cos_phi) = phi.sin_cos();
+
+        Vector3::new(r * sin_theta * sin_phi, r * cos_theta, r * sin_theta * cos_phi)
+    }
+}
+
+// Stores the polar and azimuth angles of a unit vector remapped to [0, 1].
+pub fn spherical_encode(n: Vector3) -> Vector2 {
+    let s = n.to_spherical();
+    Vector2::new(s.y / PI, s.z / (2. * PI) + 0.5)
+}
+
+pub fn spherical_decode(e: Vector2) -> Vector3 {
+    Vector3::from_spherical(Vector3::new(1., e.x * PI, (e.y - 0.5) * 2. * PI))
+}
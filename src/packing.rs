@@ -0,0 +1,221 @@
+use crate::{linear_to_srgb, srgb_to_linear, Color, Vector3, Vector4};
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use crate::math::FloatMath;
+
+#[inline]
+pub fn pack_unorm8(v: f32) -> u8 {
+    pack_unorm(v, u8::MAX as f32) as u8
+}
+
+#[inline]
+pub fn unpack_unorm8(v: u8) -> f32 {
+    v as f32 / u8::MAX as f32
+}
+
+#[inline]
+pub fn pack_snorm8(v: f32) -> i8 {
+    pack_snorm(v, i8::MAX as f32) as i8
+}
+
+#[inline]
+pub fn unpack_snorm8(v: i8) -> f32 {
+    (v as f32 / i8::MAX as f32).max(-1.)
+}
+
+#[inline]
+pub fn pack_unorm16(v: f32) -> u16 {
+    pack_unorm(v, u16::MAX as f32) as u16
+}
+
+#[inline]
+pub fn unpack_unorm16(v: u16) -> f32 {
+    v as f32 / u16::MAX as f32
+}
+
+#[inline]
+pub fn pack_snorm16(v: f32) -> i16 {
+    pack_snorm(v, i16::MAX as f32) as i16
+}
+
+#[inline]
+pub fn unpack_snorm16(v: i16) -> f32 {
+    (v as f32 / i16::MAX as f32).max(-1.)
+}
+
+#[inline]
+fn pack_unorm(v: f32, max: f32) -> f32 {
+    (v.clamp(0., 1.) * max).round()
+}
+
+#[inline]
+fn pack_snorm(v: f32, max: f32) -> f32 {
+    (v.clamp(-1., 1.) * max).round()
+}
+
+pub fn pack_rgb10a2(v: Vector4) -> u32 {
+    let r = pack_unorm(v.x, 1023.) as u32;
+    let g = pack_unorm(v.y, 1023.) as u32;
+    let b = pack_unorm(v.z, 1023.) as u32;
+    let a = pack_unorm(v.w, 3.) as u32;
+
+    r | g << 10 | b << 20 | a << 30
+}
+
+pub fn unpack_rgb10a2(v: u32) -> Vector4 {
+    Vector4::new(
+        (v & 0x3ff) as f32 / 1023.,
+        (v >> 10 & 0x3ff) as f32 / 1023.,
+        (v >> 20 & 0x3ff) as f32 / 1023.,
+        (v >> 30) as f32 / 3.,
+    )
+}
+
+pub fn pack_rgba8_srgb(v: Vector4) -> u32 {
+    u32::from_le_bytes([
+        pack_unorm8(linear_to_srgb(v.x)),
+        pack_unorm8(linear_to_srgb(v.y)),
+        pack_unorm8(linear_to_srgb(v.z)),
+        pack_unorm8(v.w),
+    ])
+}
+
+pub fn unpack_rgba8_srgb(v: u32) -> Vector4 {
+    let [r, g, b, a] = v.to_le_bytes();
+
+    Vector4::new(
+        srgb_to_linear(unpack_unorm8(r)),
+        srgb_to_linear(unpack_unorm8(g)),
+        srgb_to_linear(unpack_unorm8(b)),
+        unpack_unorm8(a),
+    )
+}
+
+impl Color {
+    #[inline]
+    pub fn to_rgba8_srgb(self) -> u32 {
+        pack_rgba8_srgb(self.into())
+    }
+
+    #[inline]
+    pub fn from_rgba8_srgb(v: u32) -> Color {
+        unpack_rgba8_srgb(v).into()
+    }
+}
+
+// Unsigned small floats with a 5 bit exponent, as used by R11G11B10F.
+const UFLOAT_EXP_BIAS: i32 = 15;
+
+#[inline]
+pub(crate) fn round_shift_even(v: u32, shift: u32) -> u32 {
+    if shift == 0 {
+        return v;
+    }
+    if shift >= 32 {
+        return 0;
+    }
+
+    let q = v >> shift;
+    let rem = v & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+
+    if rem > half || (rem == half && q & 1 == 1) {
+        q + 1
+    } else {
+        q
+    }
+}
+
+fn f32_to_ufloat(v: f32, mantissa_bits: u32) -> u32 {
+    let mantissa_mask = (1 << mantissa_bits) - 1;
+    let exp_mask = 0x1f << mantissa_bits;
+    let max_finite = (30 << mantissa_bits) | mantissa_mask;
+
+    if v.is_nan() {
+        return exp_mask | mantissa_mask;
+    }
+    if v <= 0. {
+        return 0;
+    }
+    if v.is_infinite() {
+        return exp_mask;
+    }
+
+    let bits = v.to_bits();
+    let exp = ((bits >> 23) & 0xff) as i32 - 127 + UFLOAT_EXP_BIAS;
+    let mantissa = bits & 0x7f_ffff;
+    let shift = 23 - mantissa_bits;
+
+    let packed = if exp > 0 {
+        round_shift_even((exp as u32) << 23 | mantissa, shift)
+    } else {
+        round_shift_even(mantissa | 0x80_0000, shift + (1 - exp) as u32)
+    };
+
+    packed.min(max_finite)
+}
+
+fn ufloat_to_f32(v: u32, mantissa_bits: u32) -> f32 {
+    let exp = (v >> mantissa_bits) & 0x1f;
+    let mantissa = v & ((1 << mantissa_bits) - 1);
+
+    match exp {
+        0 => mantissa as f32 * 2f32.powi(1 - UFLOAT_EXP_BIAS - mantissa_bits as i32),
+        0x1f if mantissa == 0 => f32::INFINITY,
+        0x1f => f32::NAN,
+        _ => f32::from_bits(
+            ((exp as i32 - UFLOAT_EXP_BIAS + 127) as u32) << 23 | mantissa << (23 - mantissa_bits),
+        ),
+    }
+}
+
+pub fn pack_r11g11b10f(v: Vector3) -> u32 {
+    f32_to_ufloat(v.x, 6) | f32_to_ufloat(v.y, 6) << 11 | f32_to_ufloat(v.z, 5) << 22
+}
+
+pub fn unpack_r11g11b10f(v: u32) -> Vector3 {
+    Vector3::new(
+        ufloat_to_f32(v & 0x7ff, 6),
+        ufloat_to_f32(v >> 11 & 0x7ff, 6),
+        ufloat_to_f32(v >> 22, 5),
+    )
+}
+
+const RGB9E5_MANTISSA_BITS: i32 = 9;
+const RGB9E5_EXP_BIAS: i32 = 15;
+const RGB9E5_MAX: f32 = 65408.;
+
+// Follows the reference encoder from EXT_texture_shared_exponent.
+pub fn pack_rgb9e5(v: Vector3) -> u32 {
+    let clamp = |c: f32| if c.is_nan() { 0. } else { c.clamp(0., RGB9E5_MAX) };
+    let (r, g, b) = (clamp(v.x), clamp(v.y), clamp(v.z));
+    let max = r.max(g).max(b);
+
+    let floor_log2 = if max == 0. {
+        i32::MIN
+    } else {
+        ((max.to_bits() >> 23) & 0xff) as i32 - 127
+    };
+
+    let mut exp = floor_log2.max(-RGB9E5_EXP_BIAS - 1) + 1 + RGB9E5_EXP_BIAS;
+    let scale = |exp: i32| 2f32.powi(exp - RGB9E5_EXP_BIAS - RGB9E5_MANTISSA_BITS);
+
+    if (max / scale(exp) + 0.5).floor() as i32 == 1 << RGB9E5_MANTISSA_BITS {
+        exp += 1;
+    }
+
+    let denom = scale(exp);
+    let quantize = |c: f32| (c / denom + 0.5).floor() as u32;
+
+    quantize(r) | quantize(g) << 9 | quantize(b) << 18 | (exp as u32) << 27
+}
+
+pub fn unpack_rgb9e5(v: u32) -> Vector3 {
+    let exp = (v >> 27) as i32;
+    let scale = 2f32.powi(exp - RGB9E5_EXP_BIAS - RGB9E5_MANTISSA_BITS);
+
+    Vector3::new(
+        (v & 0x1ff) as f32 * scale,
+        (v >> 9 & 0x1ff) as f32 * scale,
+        (v >> 18 & 0x1ff) as f32 * scale,
+    )
+}
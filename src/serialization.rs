@@ -0,0 +1,55 @@
+use crate::*;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+// Vectors and colors serialize as arrays of their components, matrices as 16 column-major
+// values. f16 goes through f32 so text formats stay readable; the round trip is exact.
+
+impl Serialize for f16 {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_f32().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for f16 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<f16, D::Error> {
+        f32::deserialize(deserializer).map(f16::from_f32)
+    }
+}
+
+// Serializes a matrix as four nested column arrays instead of the default flat array:
+//
+//     #[derive(Serialize, Deserialize)]
+//     struct Node {
+//         #[serde(with = "division_math::nested_columns")]
+//         transform: Matrix4x4,
+//     }
+pub mod nested_columns {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub trait MatrixColumns: Sized {
+        type Column;
+
+        fn to_columns(&self) -> [Self::Column; 4];
+        fn from_columns_array(columns: [Self::Column; 4]) -> Self;
+    }
+
+    pub fn serialize<M, S>(matrix: &M, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        M: MatrixColumns,
+        M::Column: Serialize,
+        S: Serializer,
+    {
+        matrix.to_columns().serialize(serializer)
+    }
+
+    pub fn deserialize<'de, M, D>(deserializer: D) -> Result<M, D::Error>
+    where
+        M: MatrixColumns,
+        M::Column: Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        <[M::Column; 4]>::deserialize(deserializer).map(M::from_columns_array)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/serde.gen.rs"));
@@ -0,0 +1,61 @@
+use crate::batch::Mode;
+use crate::{Matrix4x4, Vector3, Vector4};
+use core::arch::aarch64::{
+    float32x4_t, vaddq_f32, vdivq_f32, vdupq_laneq_f32, vgetq_lane_f32, vld1q_f32, vmulq_n_f32,
+    vst1q_f32,
+};
+
+// Runtime dispatched batch transform kernels. Separate multiplies and adds, in the same
+// order as the scalar product, keep the results bit-identical to it.
+
+#[inline(always)]
+unsafe fn load_columns(m: &Matrix4x4) -> [float32x4_t; 4] {
+    [
+        vld1q_f32(m[0].as_ptr()),
+        vld1q_f32(m[1].as_ptr()),
+        vld1q_f32(m[2].as_ptr()),
+        vld1q_f32(m[3].as_ptr()),
+    ]
+}
+
+#[target_feature(enable = "neon")]
+pub(crate) unsafe fn transform_vector3s_neon(
+    m: &Matrix4x4,
+    src: &[Vector3],
+    dst: &mut [Vector3],
+    mode: Mode,
+) {
+    let c = load_columns(m);
+    let t = vmulq_n_f32(c[3], mode.w());
+
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        let r = vmulq_n_f32(c[0], s.x);
+        let r = vaddq_f32(r, vmulq_n_f32(c[1], s.y));
+        let r = vaddq_f32(r, vmulq_n_f32(c[2], s.z));
+        let mut r = vaddq_f32(r, t);
+
+        if mode == Mode::PerspectiveDivide {
+            r = vdivq_f32(r, vdupq_laneq_f32::<3>(r));
+        }
+
+        *d = Vector3::new(
+            vgetq_lane_f32::<0>(r),
+            vgetq_lane_f32::<1>(r),
+            vgetq_lane_f32::<2>(r),
+        );
+    }
+}
+
+#[target_feature(enable = "neon")]
+pub(crate) unsafe fn transform_vec4s_neon(m: &Matrix4x4, src: &[Vector4], dst: &mut [Vector4]) {
+    let c = load_columns(m);
+
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        let r = vmulq_n_f32(c[0], s.x);
+        let r = vaddq_f32(r, vmulq_n_f32(c[1], s.y));
+        let r = vaddq_f32(r, vmulq_n_f32(c[2], s.z));
+        let r = vaddq_f32(r, vmulq_n_f32(c[3], s.w));
+
+        vst1q_f32(d.as_mut_ptr(), r);
+    }
+}
@@ -0,0 +1,161 @@
+use crate::batch::Mode;
+use crate::{Matrix4x4, Vector3, Vector4};
+use core::arch::x86_64::{
+    __m128, __m256, _mm256_add_ps, _mm256_castps256_ps128, _mm256_div_ps, _mm256_extractf128_ps,
+    _mm256_fmadd_ps, _mm256_mul_ps, _mm256_permute_ps, _mm256_set_m128, _mm_add_ps, _mm_div_ps,
+    _mm_fmadd_ps, _mm_loadu_ps, _mm_mul_ps, _mm_set1_ps, _mm_shuffle_ps, _mm_storeu_ps,
+};
+
+// Runtime dispatched batch transform kernels. Each element is multiplied column by column
+// in array-of-structures form, the SSE4.1 kernel in the same order as the scalar product.
+
+#[inline(always)]
+unsafe fn load_columns(m: &Matrix4x4) -> [__m128; 4] {
+    [
+        _mm_loadu_ps(m[0].as_ptr()),
+        _mm_loadu_ps(m[1].as_ptr()),
+        _mm_loadu_ps(m[2].as_ptr()),
+        _mm_loadu_ps(m[3].as_ptr()),
+    ]
+}
+
+#[inline(always)]
+unsafe fn to_vector3(v: __m128) -> Vector3 {
+    let mut lanes = [0f32; 4];
+    _mm_storeu_ps(lanes.as_mut_ptr(), v);
+
+    Vector3::new(lanes[0], lanes[1], lanes[2])
+}
+
+#[inline(always)]
+unsafe fn perspective_divide(v: __m128) -> __m128 {
+    _mm_div_ps(v, _mm_shuffle_ps::<0xff>(v, v))
+}
+
+#[inline(always)]
+unsafe fn transform_vector3_sse(c: &[__m128; 4], t: __m128, v: Vector3, mode: Mode) -> Vector3 {
+    let r = _mm_mul_ps(c[0], _mm_set1_ps(v.x));
+    let r = _mm_add_ps(r, _mm_mul_ps(c[1], _mm_set1_ps(v.y)));
+    let r = _mm_add_ps(r, _mm_mul_ps(c[2], _mm_set1_ps(v.z)));
+    let r = _mm_add_ps(r, t);
+
+    match mode {
+        Mode::PerspectiveDivide => to_vector3(perspective_divide(r)),
+        _ => to_vector3(r),
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn transform_vector3s_sse41(
+    m: &Matrix4x4,
+    src: &[Vector3],
+    dst: &mut [Vector3],
+    mode: Mode,
+) {
+    let c = load_columns(m);
+    let t = _mm_mul_ps(c[3], _mm_set1_ps(mode.w()));
+
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        *d = transform_vector3_sse(&c, t, *s, mode);
+    }
+}
+
+#[target_feature(enable = "sse4.1")]
+pub(crate) unsafe fn transform_vec4s_sse41(m: &Matrix4x4, src: &[Vector4], dst: &mut [Vector4]) {
+    let c = load_columns(m);
+
+    for (s, d) in src.iter().zip(dst.iter_mut()) {
+        let r = _mm_mul_ps(c[0], _mm_set1_ps(s.x));
+        let r = _mm_add_ps(r, _mm_mul_ps(c[1], _mm_set1_ps(s.y)));
+        let r = _mm_add_ps(r, _mm_mul_ps(c[2], _mm_set1_ps(s.z)));
+        let r = _mm_add_ps(r, _mm_mul_ps(c[3], _mm_set1_ps(s.w)));
+
+        _mm_storeu_ps(d.as_mut_ptr(), r);
+    }
+}
+
+#[inline(always)]
+unsafe fn splat_pair(lo: f32, hi: f32) -> __m256 {
+    _mm256_set_m128(_mm_set1_ps(hi), _mm_set1_ps(lo))
+}
+
+#[inline(always)]
+unsafe fn double_columns(c: &[__m128; 4]) -> [__m256; 4] {
+    [
+        _mm256_set_m128(c[0], c[0]),
+        _mm256_set_m128(c[1], c[1]),
+        _mm256_set_m128(c[2], c[2]),
+        _mm256_set_m128(c[3], c[3]),
+    ]
+}
+
+// Two elements per 256-bit register, one in each 128-bit half.
+#[target_feature(enable = "avx2,fma")]
+pub(crate) unsafe fn transform_vector3s_avx2_fma(
+    m: &Matrix4x4,
+    src: &[Vector3],
+    dst: &mut [Vector3],
+    mode: Mode,
+) {
+    let c = load_columns(m);
+    let t = _mm_mul_ps(c[3], _mm_set1_ps(mode.w()));
+    let c2 = double_columns(&c);
+    let t2 = _mm256_set_m128(t, t);
+
+    let mut src_pairs = src.chunks_exact(2);
+    let mut dst_pairs = dst.chunks_exact_mut(2);
+
+    for (s, d) in (&mut src_pairs).zip(&mut dst_pairs) {
+        let r = _mm256_mul_ps(c2[0], splat_pair(s[0].x, s[1].x));
+        let r = _mm256_fmadd_ps(c2[1], splat_pair(s[0].y, s[1].y), r);
+        let r = _mm256_fmadd_ps(c2[2], splat_pair(s[0].z, s[1].z), r);
+        let mut r = _mm256_add_ps(r, t2);
+
+        if mode == Mode::PerspectiveDivide {
+            r = _mm256_div_ps(r, _mm256_permute_ps::<0xff>(r));
+        }
+
+        d[0] = to_vector3(_mm256_castps256_ps128(r));
+        d[1] = to_vector3(_mm256_extractf128_ps::<1>(r));
+    }
+
+    for (s, d) in src_pairs.remainder().iter().zip(dst_pairs.into_remainder()) {
+        let r = _mm_mul_ps(c[0], _mm_set1_ps(s.x));
+        let r = _mm_fmadd_ps(c[1], _mm_set1_ps(s.y), r);
+        let r = _mm_fmadd_ps(c[2], _mm_set1_ps(s.z), r);
+        let r = _mm_add_ps(r, t);
+
+        *d = match mode {
+            Mode::PerspectiveDivide => to_vector3(perspective_divide(r)),
+            _ => to_vector3(r),
+        };
+    }
+}
+
+#[target_feature(enable = "avx2,fma")]
+pub(crate) unsafe fn transform_vec4s_avx2_fma(m: &Matrix4x4, src: &[Vector4], dst: &mut [Vector4]) {
+    let c = load_columns(m);
+    let c2 = double_columns(&c);
+
+    let mut src_pairs = src.chunks_exact(2);
+    let mut dst_pairs = dst.chunks_exact_mut(2);
+
+    for (s, d) in (&mut src_pairs).zip(&mut dst_pairs) {
+        let r = _mm256_mul_ps(c2[0], splat_pair(s[0].x, s[1].x));
+        let r = _mm256_fmadd_ps(c2[1], splat_pair(s[0].y, s[1].y), r);
+        let r = _mm256_fmadd_ps(c2[2], splat_pair(s[0].z, s[1].z), r);
+        let r = _mm256_fmadd_ps(c2[3], splat_pair(s[0].w, s[1].w), r);
+
+        _mm_storeu_ps(d[0].as_mut_ptr(), _mm256_castps256_ps128(r));
+        _mm_storeu_ps(d[1].as_mut_ptr(), _mm256_extractf128_ps::<1>(r));
+    }
+
+    for (s, d) in src_pairs.remainder().iter().zip(dst_pairs.into_remainder()) {
+        let r = _mm_mul_ps(c[0], _mm_set1_ps(s.x));
+        let r = _mm_fmadd_ps(c[1], _mm_set1_ps(s.y), r);
+        let r = _mm_fmadd_ps(c[2], _mm_set1_ps(s.z), r);
+        let r = _mm_fmadd_ps(c[3], _mm_set1_ps(s.w), r);
+
+        _mm_storeu_ps(d.as_mut_ptr(), r);
+    }
+}
@@ -0,0 +1,36 @@
+use crate::f16;
+use core::arch::x86_64::{
+    __m128i, _mm_cvtph_ps, _mm_cvtps_ph, _mm_loadl_epi64, _mm_loadu_ps, _mm_storel_epi64,
+    _mm_storeu_ps, _MM_FROUND_TO_NEAREST_INT,
+};
+
+// Converts four lanes at a time and returns how many elements were written. Callers check
+// for F16C first.
+#[target_feature(enable = "f16c")]
+pub(crate) unsafe fn f32_to_f16_simd(src: &[f32], dst: &mut [f16]) -> usize {
+    let len = src.len().min(dst.len()) / 4 * 4;
+
+    for i in (0..len).step_by(4) {
+        unsafe {
+            let v = _mm_loadu_ps(src.as_ptr().add(i));
+            let h = _mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(v);
+            _mm_storel_epi64(dst.as_mut_ptr().add(i) as *mut __m128i, h);
+        }
+    }
+
+    len
+}
+
+#[target_feature(enable = "f16c")]
+pub(crate) unsafe fn f16_to_f32_simd(src: &[f16], dst: &mut [f32]) -> usize {
+    let len = src.len().min(dst.len()) / 4 * 4;
+
+    for i in (0..len).step_by(4) {
+        unsafe {
+            let h = _mm_loadl_epi64(src.as_ptr().add(i) as *const __m128i);
+            _mm_storeu_ps(dst.as_mut_ptr().add(i), _mm_cvtph_ps(h));
+        }
+    }
+
+    len
+}
@@ -1,2 +1,59 @@
 #[cfg(all(feature = "enable_simd", target_feature = "neon"))]
-pub(crate) mod neon;
\ No newline at end of file
+pub(crate) mod neon;
+#[cfg(all(feature = "enable_simd", target_arch = "wasm32", target_feature = "simd128"))]
+pub(crate) mod wasm;
+#[cfg(all(
+    feature = "portable_simd",
+    not(target_feature = "neon"),
+    not(all(target_arch = "wasm32", target_feature = "simd128"))
+))]
+pub(crate) mod portable;
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod f16c;
+#[cfg(target_arch = "x86_64")]
+pub(crate) mod batch_x86;
+#[cfg(target_arch = "aarch64")]
+pub(crate) mod batch_neon;
+
+#[cfg(all(
+    feature = "enable_simd",
+    not(feature = "portable_simd"),
+    not(target_feature = "neon"),
+    not(all(target_arch = "wasm32", target_feature = "simd128"))
+))]
+compile_error!("`enable_simd` has no hand-written backend for this target, enable `portable_simd` on nightly");
+
+// The x86 path is picked at runtime like the batch kernels, so it doesn't depend on the
+// `enable_simd` backend.
+pub(crate) mod f16_convert {
+    #[cfg(target_arch = "x86_64")]
+    pub(crate) fn f32_to_f16_simd(src: &[f32], dst: &mut [crate::f16]) -> usize {
+        if crate::dispatch::has_f16c() {
+            unsafe { super::f16c::f32_to_f16_simd(src, dst) }
+        } else {
+            0
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    pub(crate) fn f16_to_f32_simd(src: &[crate::f16], dst: &mut [f32]) -> usize {
+        if crate::dispatch::has_f16c() {
+            unsafe { super::f16c::f16_to_f32_simd(src, dst) }
+        } else {
+            0
+        }
+    }
+
+    #[cfg(all(feature = "enable_simd", target_feature = "neon"))]
+    pub(crate) use super::neon::{f16_to_f32_simd, f32_to_f16_simd};
+
+    #[cfg(not(any(target_arch = "x86_64", all(feature = "enable_simd", target_feature = "neon"))))]
+    pub(crate) fn f32_to_f16_simd(_src: &[f32], _dst: &mut [crate::f16]) -> usize {
+        0
+    }
+
+    #[cfg(not(any(target_arch = "x86_64", all(feature = "enable_simd", target_feature = "neon"))))]
+    pub(crate) fn f16_to_f32_simd(_src: &[crate::f16], _dst: &mut [f32]) -> usize {
+        0
+    }
+}
@@ -1,6 +1,12 @@
-use std::arch::aarch64::{float32x4_t, vaddq_f32, vdivq_f32, vld1q_f32, vmulq_f32, vmulq_n_f32, vst1q_f32, vsubq_f32};
-use std::mem::MaybeUninit;
-use crate::Vector4;
+use core::arch::aarch64::{
+    float32x4_t, uint32x4_t, vabsq_f32, vaddq_f32, vbslq_f32, vceqq_f32, vcleq_f32, vcltq_f32,
+    vdivq_f32, vfmaq_f32, vld1q_f32, vld1q_u32, vmaxnmq_f32, vmaxnmvq_f32, vminnmq_f32,
+    vminnmvq_f32, vmulq_f32, vmulq_n_f32, vrndaq_f32, vrndmq_f32, vrndpq_f32, vrndq_f32,
+    vsqrtq_f32, vst1q_f32, vst1q_u32, vsubq_f32,
+};
+use core::arch::asm;
+use core::mem::MaybeUninit;
+use crate::{f16, BVector4, Vector4};
 
 #[cfg(target_feature = "neon")]
 impl Vector4 {
@@ -63,6 +69,110 @@ impl Vector4 {
         }
     }
 
+    #[inline(always)]
+    pub(crate) fn abs_simd(v: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vabsq_f32(v.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn floor_simd(v: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vrndmq_f32(v.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn ceil_simd(v: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vrndpq_f32(v.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn round_simd(v: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vrndaq_f32(v.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn trunc_simd(v: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vrndq_f32(v.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn sqrt_simd(v: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vsqrtq_f32(v.load_to_neon())) }
+    }
+
+    // FMINNM/FMAXNM return the non-NaN operand, matching f32::min and f32::max.
+    #[inline(always)]
+    pub(crate) fn min_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vminnmq_f32(lhs.load_to_neon(), rhs.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn max_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        unsafe { Vector4::from_neon_ptr(vmaxnmq_f32(lhs.load_to_neon(), rhs.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn min_element_simd(v: Vector4) -> f32 {
+        unsafe { vminnmvq_f32(v.load_to_neon()) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn max_element_simd(v: Vector4) -> f32 {
+        unsafe { vmaxnmvq_f32(v.load_to_neon()) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_add_simd(v: Vector4, a: Vector4, b: Vector4) -> Vector4 {
+        unsafe {
+            let r = vfmaq_f32(b.load_to_neon(), v.load_to_neon(), a.load_to_neon());
+
+            Vector4::from_neon_ptr(r)
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmpeq_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        unsafe { Vector4::mask_from_neon(vceqq_f32(lhs.load_to_neon(), rhs.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmplt_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        unsafe { Vector4::mask_from_neon(vcltq_f32(lhs.load_to_neon(), rhs.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmple_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        unsafe { Vector4::mask_from_neon(vcleq_f32(lhs.load_to_neon(), rhs.load_to_neon())) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn select_simd(mask: BVector4, a: Vector4, b: Vector4) -> Vector4 {
+        unsafe {
+            let r = vbslq_f32(Vector4::mask_to_neon(mask), a.load_to_neon(), b.load_to_neon());
+
+            Vector4::from_neon_ptr(r)
+        }
+    }
+
+    #[inline(always)]
+    fn mask_from_neon(mask: uint32x4_t) -> BVector4 {
+        let mut lanes = [0u32; 4];
+        unsafe { vst1q_u32(lanes.as_mut_ptr(), mask) };
+
+        BVector4::new(lanes[0] != 0, lanes[1] != 0, lanes[2] != 0, lanes[3] != 0)
+    }
+
+    #[inline(always)]
+    fn mask_to_neon(mask: BVector4) -> uint32x4_t {
+        let lanes = [
+            0u32.wrapping_sub(mask.x as u32),
+            0u32.wrapping_sub(mask.y as u32),
+            0u32.wrapping_sub(mask.z as u32),
+            0u32.wrapping_sub(mask.w as u32),
+        ];
+
+        unsafe { vld1q_u32(lanes.as_ptr()) }
+    }
+
     #[inline(always)]
     fn from_neon_ptr(neon_ptr: float32x4_t) -> Vector4 {
         unsafe {
@@ -78,4 +188,56 @@ impl Vector4 {
             vld1q_f32(self.as_ptr())
         }
     }
-}
\ No newline at end of file
+}
+
+// FCVTN/FCVTL are part of the base AArch64 FP set; the f16 NEON intrinsics that
+// wrap them are still unstable, so they are emitted directly.
+#[inline]
+pub(crate) fn f32_to_f16_simd(src: &[f32], dst: &mut [f16]) -> usize {
+    let len = src.len().min(dst.len()) / 4 * 4;
+
+    for i in (0..len).step_by(4) {
+        unsafe {
+            let v = vld1q_f32(src.as_ptr().add(i));
+            let packed: u64;
+
+            asm!(
+                "fcvtn {tmp:v}.4h, {v:v}.4s",
+                "fmov {packed}, {tmp:d}",
+                v = in(vreg) v,
+                tmp = out(vreg) _,
+                packed = out(reg) packed,
+                options(pure, nomem, nostack),
+            );
+
+            (dst.as_mut_ptr().add(i) as *mut u64).write_unaligned(packed.to_le());
+        }
+    }
+
+    len
+}
+
+#[inline]
+pub(crate) fn f16_to_f32_simd(src: &[f16], dst: &mut [f32]) -> usize {
+    let len = src.len().min(dst.len()) / 4 * 4;
+
+    for i in (0..len).step_by(4) {
+        unsafe {
+            let packed = u64::from_le((src.as_ptr().add(i) as *const u64).read_unaligned());
+            let v: float32x4_t;
+
+            asm!(
+                "fmov {tmp:d}, {packed}",
+                "fcvtl {v:v}.4s, {tmp:v}.4h",
+                packed = in(reg) packed,
+                tmp = out(vreg) _,
+                v = out(vreg) v,
+                options(pure, nomem, nostack),
+            );
+
+            vst1q_f32(dst.as_mut_ptr().add(i), v);
+        }
+    }
+
+    len
+}
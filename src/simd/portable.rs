@@ -0,0 +1,167 @@
+use core::simd::prelude::{f32x4, mask32x4, SimdFloat, SimdPartialEq, SimdPartialOrd};
+#[cfg(feature = "std")]
+use std::simd::StdFloat;
+use core::simd::Select;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use crate::math::FloatMath;
+use crate::{BVector4, Vector4};
+
+// Target independent backend on top of `core::simd`, used wherever no hand-written
+// intrinsics exist. Lane semantics match the scalar ops generated by build.rs.
+// `StdFloat` lives in std, so without it these run lane by lane through libm.
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+trait LaneMath {
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn round(self) -> Self;
+    fn trunc(self) -> Self;
+    fn sqrt(self) -> Self;
+    fn mul_add(self, a: Self, b: Self) -> Self;
+}
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl LaneMath for f32x4 {
+    #[inline(always)]
+    fn floor(self) -> f32x4 { f32x4::from_array(self.to_array().map(FloatMath::floor)) }
+    #[inline(always)]
+    fn ceil(self) -> f32x4 { f32x4::from_array(self.to_array().map(FloatMath::ceil)) }
+    #[inline(always)]
+    fn round(self) -> f32x4 { f32x4::from_array(self.to_array().map(FloatMath::round)) }
+    #[inline(always)]
+    fn trunc(self) -> f32x4 { f32x4::from_array(self.to_array().map(FloatMath::trunc)) }
+    #[inline(always)]
+    fn sqrt(self) -> f32x4 { f32x4::from_array(self.to_array().map(FloatMath::sqrt)) }
+    #[inline(always)]
+    fn mul_add(self, a: f32x4, b: f32x4) -> f32x4 {
+        let (v, a, b) = (self.to_array(), a.to_array(), b.to_array());
+        f32x4::from_array(core::array::from_fn(|i| v[i].mul_add(a[i], b[i])))
+    }
+}
+
+impl Vector4 {
+    #[inline(always)]
+    pub(crate) fn mul_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd() * rhs.to_simd())
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_scalar_simd(lhs: Vector4, rhs: f32) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd() * f32x4::splat(rhs))
+    }
+
+    #[inline(always)]
+    pub(crate) fn div_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd() / rhs.to_simd())
+    }
+
+    #[inline(always)]
+    pub(crate) fn div_scalar_simd(lhs: Vector4, rhs: f32) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd() / f32x4::splat(rhs))
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd() + rhs.to_simd())
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd() - rhs.to_simd())
+    }
+
+    #[inline(always)]
+    pub(crate) fn abs_simd(v: Vector4) -> Vector4 {
+        Vector4::from_simd(v.to_simd().abs())
+    }
+
+    #[inline(always)]
+    pub(crate) fn floor_simd(v: Vector4) -> Vector4 {
+        Vector4::from_simd(v.to_simd().floor())
+    }
+
+    #[inline(always)]
+    pub(crate) fn ceil_simd(v: Vector4) -> Vector4 {
+        Vector4::from_simd(v.to_simd().ceil())
+    }
+
+    #[inline(always)]
+    pub(crate) fn round_simd(v: Vector4) -> Vector4 {
+        Vector4::from_simd(v.to_simd().round())
+    }
+
+    #[inline(always)]
+    pub(crate) fn trunc_simd(v: Vector4) -> Vector4 {
+        Vector4::from_simd(v.to_simd().trunc())
+    }
+
+    #[inline(always)]
+    pub(crate) fn sqrt_simd(v: Vector4) -> Vector4 {
+        Vector4::from_simd(v.to_simd().sqrt())
+    }
+
+    #[inline(always)]
+    pub(crate) fn min_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd().simd_min(rhs.to_simd()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn max_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_simd(lhs.to_simd().simd_max(rhs.to_simd()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn min_element_simd(v: Vector4) -> f32 {
+        v.to_simd().reduce_min()
+    }
+
+    #[inline(always)]
+    pub(crate) fn max_element_simd(v: Vector4) -> f32 {
+        v.to_simd().reduce_max()
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_add_simd(v: Vector4, a: Vector4, b: Vector4) -> Vector4 {
+        Vector4::from_simd(v.to_simd().mul_add(a.to_simd(), b.to_simd()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmpeq_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        Vector4::mask_from_simd(lhs.to_simd().simd_eq(rhs.to_simd()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmplt_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        Vector4::mask_from_simd(lhs.to_simd().simd_lt(rhs.to_simd()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmple_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        Vector4::mask_from_simd(lhs.to_simd().simd_le(rhs.to_simd()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn select_simd(mask: BVector4, a: Vector4, b: Vector4) -> Vector4 {
+        let mask = mask32x4::from_array([mask.x, mask.y, mask.z, mask.w]);
+
+        Vector4::from_simd(mask.select(a.to_simd(), b.to_simd()))
+    }
+
+    #[inline(always)]
+    fn mask_from_simd(mask: mask32x4) -> BVector4 {
+        let [x, y, z, w] = mask.to_array();
+
+        BVector4::new(x, y, z, w)
+    }
+
+    #[inline(always)]
+    fn from_simd(v: f32x4) -> Vector4 {
+        let [x, y, z, w] = v.to_array();
+
+        Vector4::new(x, y, z, w)
+    }
+
+    #[inline(always)]
+    fn to_simd(self) -> f32x4 {
+        f32x4::from_array([self.x, self.y, self.z, self.w])
+    }
+}
@@ -0,0 +1,215 @@
+use core::arch::wasm32::{
+    f32x4, f32x4_abs, f32x4_add, f32x4_ceil, f32x4_div, f32x4_eq, f32x4_extract_lane,
+    f32x4_floor, f32x4_ge, f32x4_le, f32x4_lt, f32x4_min, f32x4_max, f32x4_mul, f32x4_ne,
+    f32x4_splat, f32x4_sqrt, f32x4_sub, f32x4_trunc, i32x4, i32x4_bitmask, i32x4_shuffle, v128,
+    v128_and, v128_bitselect, v128_or,
+};
+use core::ops::Mul;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use crate::math::FloatMath;
+use crate::{BVector4, Matrix4x4, Vector4};
+
+impl Vector4 {
+    #[inline(always)]
+    pub(crate) fn mul_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_mul(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn mul_scalar_simd(lhs: Vector4, rhs: f32) -> Vector4 {
+        Vector4::from_wasm(f32x4_mul(lhs.to_wasm(), f32x4_splat(rhs)))
+    }
+
+    #[inline(always)]
+    pub(crate) fn div_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_div(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn div_scalar_simd(lhs: Vector4, rhs: f32) -> Vector4 {
+        Vector4::from_wasm(f32x4_div(lhs.to_wasm(), f32x4_splat(rhs)))
+    }
+
+    #[inline(always)]
+    pub(crate) fn add_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_add(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn sub_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_sub(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn abs_simd(v: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_abs(v.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn floor_simd(v: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_floor(v.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn ceil_simd(v: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_ceil(v.to_wasm()))
+    }
+
+    // f32x4_nearest rounds ties to even, f32::round rounds them away from zero.
+    #[inline(always)]
+    pub(crate) fn round_simd(v: Vector4) -> Vector4 {
+        let v = v.to_wasm();
+        let t = f32x4_trunc(v);
+        let away = f32x4_ge(f32x4_abs(f32x4_sub(v, t)), f32x4_splat(0.5));
+        let signed_one = v128_or(v128_and(v, f32x4_splat(-0.)), f32x4_splat(1.));
+
+        Vector4::from_wasm(v128_bitselect(f32x4_add(t, signed_one), t, away))
+    }
+
+    #[inline(always)]
+    pub(crate) fn trunc_simd(v: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_trunc(v.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn sqrt_simd(v: Vector4) -> Vector4 {
+        Vector4::from_wasm(f32x4_sqrt(v.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn min_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_wasm(min_num(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn max_simd(lhs: Vector4, rhs: Vector4) -> Vector4 {
+        Vector4::from_wasm(max_num(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn min_element_simd(v: Vector4) -> f32 {
+        let v = v.to_wasm();
+        let m = min_num(v, i32x4_shuffle::<2, 3, 0, 1>(v, v));
+        let m = min_num(m, i32x4_shuffle::<1, 0, 3, 2>(m, m));
+
+        f32x4_extract_lane::<0>(m)
+    }
+
+    #[inline(always)]
+    pub(crate) fn max_element_simd(v: Vector4) -> f32 {
+        let v = v.to_wasm();
+        let m = max_num(v, i32x4_shuffle::<2, 3, 0, 1>(v, v));
+        let m = max_num(m, i32x4_shuffle::<1, 0, 3, 2>(m, m));
+
+        f32x4_extract_lane::<0>(m)
+    }
+
+    // simd128 has no fused multiply-add, so keep the single rounding of the scalar version.
+    #[inline(always)]
+    pub(crate) fn mul_add_simd(v: Vector4, a: Vector4, b: Vector4) -> Vector4 {
+        Vector4::new(
+            v.x.mul_add(a.x, b.x),
+            v.y.mul_add(a.y, b.y),
+            v.z.mul_add(a.z, b.z),
+            v.w.mul_add(a.w, b.w),
+        )
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmpeq_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        Vector4::mask_from_wasm(f32x4_eq(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmplt_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        Vector4::mask_from_wasm(f32x4_lt(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn cmple_simd(lhs: Vector4, rhs: Vector4) -> BVector4 {
+        Vector4::mask_from_wasm(f32x4_le(lhs.to_wasm(), rhs.to_wasm()))
+    }
+
+    #[inline(always)]
+    pub(crate) fn select_simd(mask: BVector4, a: Vector4, b: Vector4) -> Vector4 {
+        let mask = i32x4(-(mask.x as i32), -(mask.y as i32), -(mask.z as i32), -(mask.w as i32));
+
+        Vector4::from_wasm(v128_bitselect(a.to_wasm(), b.to_wasm(), mask))
+    }
+
+    #[inline(always)]
+    fn mask_from_wasm(mask: v128) -> BVector4 {
+        let bits = i32x4_bitmask(mask);
+
+        BVector4::new(bits & 1 != 0, bits & 2 != 0, bits & 4 != 0, bits & 8 != 0)
+    }
+
+    #[inline(always)]
+    fn from_wasm(v: v128) -> Vector4 {
+        Vector4::new(
+            f32x4_extract_lane::<0>(v),
+            f32x4_extract_lane::<1>(v),
+            f32x4_extract_lane::<2>(v),
+            f32x4_extract_lane::<3>(v),
+        )
+    }
+
+    #[inline(always)]
+    fn to_wasm(self) -> v128 {
+        f32x4(self.x, self.y, self.z, self.w)
+    }
+}
+
+// f32x4_min/max propagate NaN, f32::min/max return the other operand instead.
+#[inline(always)]
+fn min_num(a: v128, b: v128) -> v128 {
+    let m = v128_bitselect(b, f32x4_min(a, b), f32x4_ne(a, a));
+    v128_bitselect(a, m, f32x4_ne(b, b))
+}
+
+#[inline(always)]
+fn max_num(a: v128, b: v128) -> v128 {
+    let m = v128_bitselect(b, f32x4_max(a, b), f32x4_ne(a, a));
+    v128_bitselect(a, m, f32x4_ne(b, b))
+}
+
+// Column-wise products, summed in the same order as the generated scalar code.
+#[inline(always)]
+fn mul_columns(m: [v128; 4], v: Vector4) -> v128 {
+    let r = f32x4_mul(m[0], f32x4_splat(v.x));
+    let r = f32x4_add(r, f32x4_mul(m[1], f32x4_splat(v.y)));
+    let r = f32x4_add(r, f32x4_mul(m[2], f32x4_splat(v.z)));
+    f32x4_add(r, f32x4_mul(m[3], f32x4_splat(v.w)))
+}
+
+impl Matrix4x4 {
+    #[inline(always)]
+    fn columns_to_wasm(&self) -> [v128; 4] {
+        [self[0].to_wasm(), self[1].to_wasm(), self[2].to_wasm(), self[3].to_wasm()]
+    }
+}
+
+impl Mul<Matrix4x4> for Matrix4x4 {
+    type Output = Matrix4x4;
+
+    #[inline]
+    fn mul(self, rhs: Matrix4x4) -> Matrix4x4 {
+        let a = self.columns_to_wasm();
+
+        Matrix4x4::from_columns(
+            Vector4::from_wasm(mul_columns(a, rhs[0])),
+            Vector4::from_wasm(mul_columns(a, rhs[1])),
+            Vector4::from_wasm(mul_columns(a, rhs[2])),
+            Vector4::from_wasm(mul_columns(a, rhs[3])),
+        )
+    }
+}
+
+impl Mul<Vector4> for Matrix4x4 {
+    type Output = Vector4;
+
+    #[inline]
+    fn mul(self, rhs: Vector4) -> Vector4 {
+        Vector4::from_wasm(mul_columns(self.columns_to_wasm(), rhs))
+    }
+}
@@ -0,0 +1,17 @@
+use crate::{
+    DVector2, DVector3, DVector4, IVector2, IVector3, IVector4, UVector2, UVector3, UVector4,
+    Vector2, Vector3, Vector4,
+};
+
+include!(concat!(env!("OUT_DIR"), "/swizzle/vector2.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/vector3.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/vector4.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/dvector2.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/dvector3.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/dvector4.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/ivector2.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/ivector3.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/ivector4.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/uvector2.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/uvector3.gen.rs"));
+include!(concat!(env!("OUT_DIR"), "/swizzle/uvector4.gen.rs"));
@@ -1,7 +1,7 @@
-use std::fmt::Debug;
+use core::fmt::Debug;
 
 #[repr(C)]
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
 pub struct Vector2 {
     pub x: f32,
     pub y: f32,
@@ -1,7 +1,7 @@
 use crate::Vector4;
-use std::fmt::Debug;
+use core::fmt::Debug;
 
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
 #[repr(C)]
 pub struct Vector3 {
     pub x: f32,
@@ -11,7 +11,7 @@ pub struct Vector3 {
 
 impl Vector3 {
     #[inline]
-    pub fn forward() -> Vector3 {
+    pub const fn forward() -> Vector3 {
         Vector3 {
             x: 0.,
             y: 0.,
@@ -19,7 +19,7 @@ impl Vector3 {
         }
     }
     #[inline]
-    pub fn right() -> Vector3 {
+    pub const fn right() -> Vector3 {
         Vector3 {
             x: 1.,
             y: 0.,
@@ -27,7 +27,7 @@ impl Vector3 {
         }
     }
     #[inline]
-    pub fn up() -> Vector3 {
+    pub const fn up() -> Vector3 {
         Vector3 {
             x: 0.,
             y: 1.,
@@ -35,16 +35,6 @@ impl Vector3 {
         }
     }
 
-    #[inline]
-    pub fn cross(l: Vector3, r: Vector3) -> Vector3 {
-        let c = l * Vector3::new(r.y, r.z, r.x) - Vector3::new(l.y, l.z, l.x) * r;
-        Vector3::new(c.y, c.z, c.x)
-    }
-    #[inline]
-    pub fn dot(l: Vector3, r: Vector3) -> f32 {
-        l.x * r.x + l.y * r.y + l.z * r.z
-    }
-
     #[inline(always)]
     pub fn r(self) -> f32 {
         self.x
@@ -0,0 +1,63 @@
+use crate::*;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use crate::math::FloatMath;
+use core::fmt::{Debug, Formatter};
+
+// A Vector3 padded to a full 16 byte lane. `w` is a real field that is always 0, so the value
+// is bit for bit a Vector4 with w = 0 and the generated ops run as the Vector4 ones, SIMD
+// backends included.
+#[derive(PartialEq, Copy, Clone, Default)]
+#[repr(C, align(16))]
+pub struct Vector3A {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+    w: f32,
+}
+
+impl Vector3A {
+    #[inline]
+    pub fn to_vec4_as_point(self) -> Vector4 {
+        Vector4::new(self.x, self.y, self.z, 1.)
+    }
+
+    // Free, it's the same 16 bytes.
+    #[inline]
+    pub fn to_vec4_as_direction(self) -> Vector4 {
+        Vector4::new(self.x, self.y, self.z, self.w)
+    }
+}
+
+impl Debug for Vector3A {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Vector3A")
+            .field("x", &self.x)
+            .field("y", &self.y)
+            .field("z", &self.z)
+            .finish()
+    }
+}
+
+impl From<Vector3> for Vector3A {
+    #[inline]
+    fn from(v: Vector3) -> Vector3A {
+        Vector3A::new(v.x, v.y, v.z)
+    }
+}
+
+impl From<Vector3A> for Vector3 {
+    #[inline]
+    fn from(v: Vector3A) -> Vector3 {
+        Vector3::new(v.x, v.y, v.z)
+    }
+}
+
+// Drops w, use `to_vec4_as_point`/`to_vec4_as_direction` to go the other way.
+impl From<Vector4> for Vector3A {
+    #[inline]
+    fn from(v: Vector4) -> Vector3A {
+        Vector3A::new(v.x, v.y, v.z)
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/vector3a.gen.rs"));
@@ -1,4 +1,7 @@
-#[derive(PartialEq, Copy, Clone, Debug)]
+#[cfg(simd_backend)]
+use crate::BVector4;
+
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
 #[repr(C)]
 pub struct Vector4 {
     pub x: f32,
@@ -8,11 +11,6 @@ pub struct Vector4 {
 }
 
 impl Vector4 {
-    #[inline]
-    pub fn dot(l: Vector4, r: Vector4) -> f32 {
-        l.x * r.x + l.y * r.y + l.z * r.z + l.w * r.w
-    }
-
     #[inline(always)]
     pub fn r(self) -> f32 {
         self.x
@@ -31,8 +29,8 @@ impl Vector4 {
     }
 }
 
-#[cfg(feature = "enable_simd")]
-impl std::ops::Add<Vector4> for Vector4 {
+#[cfg(simd_backend)]
+impl core::ops::Add<Vector4> for Vector4 {
     type Output = Vector4;
 
     #[inline]
@@ -41,8 +39,8 @@ impl std::ops::Add<Vector4> for Vector4 {
     }
 }
 
-#[cfg(feature = "enable_simd")]
-impl std::ops::Sub<Vector4> for Vector4 {
+#[cfg(simd_backend)]
+impl core::ops::Sub<Vector4> for Vector4 {
     type Output = Vector4;
 
     #[inline]
@@ -51,8 +49,8 @@ impl std::ops::Sub<Vector4> for Vector4 {
     }
 }
 
-#[cfg(feature = "enable_simd")]
-impl std::ops::Mul<Vector4> for Vector4 {
+#[cfg(simd_backend)]
+impl core::ops::Mul<Vector4> for Vector4 {
     type Output = Vector4;
 
     #[inline]
@@ -61,8 +59,8 @@ impl std::ops::Mul<Vector4> for Vector4 {
     }
 }
 
-#[cfg(feature = "enable_simd")]
-impl std::ops::Mul<f32> for Vector4 {
+#[cfg(simd_backend)]
+impl core::ops::Mul<f32> for Vector4 {
     type Output = Vector4;
 
     #[inline]
@@ -71,8 +69,8 @@ impl std::ops::Mul<f32> for Vector4 {
     }
 }
 
-#[cfg(feature = "enable_simd")]
-impl std::ops::Div<Vector4> for Vector4 {
+#[cfg(simd_backend)]
+impl core::ops::Div<Vector4> for Vector4 {
     type Output = Vector4;
 
     #[inline]
@@ -81,12 +79,108 @@ impl std::ops::Div<Vector4> for Vector4 {
     }
 }
 
-#[cfg(feature = "enable_simd")]
-impl std::ops::Div<f32> for Vector4 {
+#[cfg(simd_backend)]
+impl core::ops::Div<f32> for Vector4 {
     type Output = Vector4;
 
     #[inline]
     fn div(self, rhs: f32) -> Self::Output {
         Vector4::div_scalar_simd(self, rhs)
     }
-}
\ No newline at end of file
+}
+
+#[cfg(simd_backend)]
+impl Vector4 {
+    #[inline]
+    pub fn cmpeq(self, rhs: Vector4) -> BVector4 {
+        Vector4::cmpeq_simd(self, rhs)
+    }
+
+    #[inline]
+    pub fn cmpne(self, rhs: Vector4) -> BVector4 {
+        !Vector4::cmpeq_simd(self, rhs)
+    }
+
+    #[inline]
+    pub fn cmplt(self, rhs: Vector4) -> BVector4 {
+        Vector4::cmplt_simd(self, rhs)
+    }
+
+    #[inline]
+    pub fn cmple(self, rhs: Vector4) -> BVector4 {
+        Vector4::cmple_simd(self, rhs)
+    }
+
+    #[inline]
+    pub fn cmpgt(self, rhs: Vector4) -> BVector4 {
+        Vector4::cmplt_simd(rhs, self)
+    }
+
+    #[inline]
+    pub fn cmpge(self, rhs: Vector4) -> BVector4 {
+        Vector4::cmple_simd(rhs, self)
+    }
+
+    #[inline]
+    pub fn select(mask: BVector4, a: Vector4, b: Vector4) -> Vector4 {
+        Vector4::select_simd(mask, a, b)
+    }
+}
+
+#[cfg(simd_backend)]
+impl Vector4 {
+    #[inline]
+    pub fn abs(self) -> Vector4 {
+        Vector4::abs_simd(self)
+    }
+
+    #[inline]
+    pub fn floor(self) -> Vector4 {
+        Vector4::floor_simd(self)
+    }
+
+    #[inline]
+    pub fn ceil(self) -> Vector4 {
+        Vector4::ceil_simd(self)
+    }
+
+    #[inline]
+    pub fn round(self) -> Vector4 {
+        Vector4::round_simd(self)
+    }
+
+    #[inline]
+    pub fn trunc(self) -> Vector4 {
+        Vector4::trunc_simd(self)
+    }
+
+    #[inline]
+    pub fn sqrt(self) -> Vector4 {
+        Vector4::sqrt_simd(self)
+    }
+
+    #[inline]
+    pub fn min(self, rhs: Vector4) -> Vector4 {
+        Vector4::min_simd(self, rhs)
+    }
+
+    #[inline]
+    pub fn max(self, rhs: Vector4) -> Vector4 {
+        Vector4::max_simd(self, rhs)
+    }
+
+    #[inline]
+    pub fn min_element(self) -> f32 {
+        Vector4::min_element_simd(self)
+    }
+
+    #[inline]
+    pub fn max_element(self) -> f32 {
+        Vector4::max_element_simd(self)
+    }
+
+    #[inline]
+    pub fn mul_add(self, a: Vector4, b: Vector4) -> Vector4 {
+        Vector4::mul_add_simd(self, a, b)
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{BVector4, Vector3, Vector4};
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+// Four lanes of x, y and z. Every wide type is processed in chunks of this size so the
+// Vector4 ops, and through them the SIMD backends, do the actual work.
+#[derive(Copy, Clone)]
+struct Chunk3 {
+    x: Vector4,
+    y: Vector4,
+    z: Vector4,
+}
+
+impl Chunk3 {
+    #[inline(always)]
+    fn load(x: &[f32], y: &[f32], z: &[f32], i: usize) -> Chunk3 {
+        Chunk3 {
+            x: load_lanes(x, i),
+            y: load_lanes(y, i),
+            z: load_lanes(z, i),
+        }
+    }
+
+    #[inline(always)]
+    fn store(self, x: &mut [f32], y: &mut [f32], z: &mut [f32], i: usize) {
+        store_lanes(self.x, x, i);
+        store_lanes(self.y, y, i);
+        store_lanes(self.z, z, i);
+    }
+
+    #[inline(always)]
+    fn map(self, f: impl Fn(Vector4) -> Vector4) -> Chunk3 {
+        Chunk3 {
+            x: f(self.x),
+            y: f(self.y),
+            z: f(self.z),
+        }
+    }
+
+    #[inline(always)]
+    fn zip(self, rhs: Chunk3, f: impl Fn(Vector4, Vector4) -> Vector4) -> Chunk3 {
+        Chunk3 {
+            x: f(self.x, rhs.x),
+            y: f(self.y, rhs.y),
+            z: f(self.z, rhs.z),
+        }
+    }
+
+    #[inline(always)]
+    fn dot(l: Chunk3, r: Chunk3) -> Vector4 {
+        l.x * r.x + l.y * r.y + l.z * r.z
+    }
+
+    #[inline(always)]
+    fn cross(l: Chunk3, r: Chunk3) -> Chunk3 {
+        Chunk3 {
+            x: l.y * r.z - l.z * r.y,
+            y: l.z * r.x - l.x * r.z,
+            z: l.x * r.y - l.y * r.x,
+        }
+    }
+
+    #[inline(always)]
+    fn select(mask: BVector4, a: Chunk3, b: Chunk3) -> Chunk3 {
+        a.zip(b, |a, b| Vector4::select(mask, a, b))
+    }
+
+    #[inline(always)]
+    fn cmpeq(self, rhs: Chunk3) -> BVector4 {
+        self.x.cmpeq(rhs.x) & self.y.cmpeq(rhs.y) & self.z.cmpeq(rhs.z)
+    }
+}
+
+#[inline(always)]
+fn load_lanes(lanes: &[f32], i: usize) -> Vector4 {
+    Vector4::new(lanes[i], lanes[i + 1], lanes[i + 2], lanes[i + 3])
+}
+
+#[inline(always)]
+fn store_lanes(v: Vector4, lanes: &mut [f32], i: usize) {
+    lanes[i..i + 4].copy_from_slice(&[v.x, v.y, v.z, v.w]);
+}
+
+#[inline(always)]
+fn map_lanes<const N: usize>(f: impl Fn(usize) -> Vector4) -> [f32; N] {
+    let mut result = [0.; N];
+
+    for i in (0..N).step_by(4) {
+        store_lanes(f(i), &mut result, i);
+    }
+
+    result
+}
+
+#[inline(always)]
+fn compare_lanes<const N: usize>(f: impl Fn(usize) -> BVector4) -> u32 {
+    (0..N).step_by(4).fold(0, |bits, i| bits | f(i).bitmask() << i)
+}
+
+#[inline(always)]
+fn mask_lanes(bits: u32, i: usize) -> BVector4 {
+    let bits = bits >> i;
+    BVector4::new(bits & 1 != 0, bits & 2 != 0, bits & 4 != 0, bits & 8 != 0)
+}
+
+include!(concat!(env!("OUT_DIR"), "/wide.gen.rs"));
@@ -0,0 +1,148 @@
+mod tests {
+    use division_math::{
+        transform_points, transform_points_in_place, transform_points_perspective,
+        transform_points_perspective_in_place, transform_vec4s, transform_vec4s_in_place, transform_vectors,
+        transform_vectors_in_place, Matrix4x4, Vector3, Vector4,
+    };
+
+    // Sum of the absolute terms of `m * v`. The batch functions run on the detected backend,
+    // whose fused multiply-adds round once per step, so the error is bounded by this rather
+    // than by the magnitude of the result.
+    fn term_scale(m: &Matrix4x4, v: Vector4) -> Vector4 {
+        Matrix4x4::from_columns(m[0].abs(), m[1].abs(), m[2].abs(), m[3].abs()) * v.abs()
+    }
+
+    fn assert_close(actual: &[f32], expected: &[f32], scale: &[f32]) {
+        for ((a, e), s) in actual.iter().zip(expected).zip(scale) {
+            assert!((a - e).abs() <= 8. * f32::EPSILON * s, "{actual:?} != {expected:?}");
+        }
+    }
+
+    fn xyz(v: Vector4) -> [f32; 3] {
+        [v.x, v.y, v.z]
+    }
+
+    fn vector3s(count: usize) -> Vec<Vector3> {
+        (0..count)
+            .map(|i| {
+                let i = i as f32;
+                Vector3::new(i * 1.5 - 4., 2. - i * 0.75, i * i * 0.25 - 1.)
+            })
+            .collect()
+    }
+
+    // Every length up to 11 so the SIMD body and all remainder sizes are covered.
+    #[test]
+    fn points_match_per_element_product() {
+        let m = Matrix4x4::from_columns(
+            Vector4::new(0.8, -0.3, 0.5, 0.01),
+            Vector4::new(0.2, 1.1, -0.4, 0.02),
+            Vector4::new(-0.6, 0.25, 0.9, 0.5),
+            Vector4::new(3., -2., 7., 1.5),
+        );
+
+        for count in 0..12 {
+            let src = vector3s(count);
+            let mut dst = vec![Vector3::zero(); count];
+            transform_points(&m, &src, &mut dst);
+
+            for (s, d) in src.iter().zip(&dst) {
+                let p = s.to_vec4_as_point();
+                assert_close(&d.to_array(), &xyz(m * p), &xyz(term_scale(&m, p)));
+            }
+
+            let mut in_place = src.clone();
+            transform_points_in_place(&m, &mut in_place);
+            assert_eq!(in_place, dst);
+        }
+    }
+
+    #[test]
+    fn vectors_ignore_translation() {
+        let m = Matrix4x4::from_columns(
+            Vector4::new(0.8, -0.3, 0.5, 0.01),
+            Vector4::new(0.2, 1.1, -0.4, 0.02),
+            Vector4::new(-0.6, 0.25, 0.9, 0.5),
+            Vector4::new(3., -2., 7., 1.5),
+        );
+
+        for count in 0..12 {
+            let src = vector3s(count);
+            let mut dst = vec![Vector3::zero(); count];
+            transform_vectors(&m, &src, &mut dst);
+
+            for (s, d) in src.iter().zip(&dst) {
+                let v = s.to_vec4_as_direction();
+                assert_close(&d.to_array(), &xyz(m * v), &xyz(term_scale(&m, v)));
+            }
+
+            let mut in_place = src.clone();
+            transform_vectors_in_place(&m, &mut in_place);
+            assert_eq!(in_place, dst);
+        }
+
+        let mut v = [Vector3::new(1., 2., 3.)];
+        transform_vectors_in_place(&Matrix4x4::translation(Vector3::all(5.)), &mut v);
+        assert_eq!(v[0], Vector3::new(1., 2., 3.));
+    }
+
+    #[test]
+    fn perspective_divides_by_w() {
+        let m = Matrix4x4::from_columns(
+            Vector4::new(0.8, -0.3, 0.5, 0.01),
+            Vector4::new(0.2, 1.1, -0.4, 0.02),
+            Vector4::new(-0.6, 0.25, 0.9, 0.5),
+            Vector4::new(3., -2., 7., 1.5),
+        );
+
+        for count in 0..12 {
+            let src = vector3s(count);
+            let mut dst = vec![Vector3::zero(); count];
+            transform_points_perspective(&m, &src, &mut dst);
+
+            for (s, d) in src.iter().zip(&dst) {
+                let p = s.to_vec4_as_point();
+                let r = m * p;
+                let expected = Vector3::new(r.x, r.y, r.z) / r.w;
+                // The error of w carries over through the divide.
+                let t = term_scale(&m, p);
+                let scale = (Vector3::new(t.x, t.y, t.z) + expected.abs() * t.w) / r.w.abs();
+                assert_close(&d.to_array(), &expected.to_array(), &scale.to_array());
+            }
+
+            let mut in_place = src.clone();
+            transform_points_perspective_in_place(&m, &mut in_place);
+            assert_eq!(in_place, dst);
+        }
+    }
+
+    #[test]
+    fn vec4s_match_per_element_product() {
+        let m = Matrix4x4::from_columns(
+            Vector4::new(0.8, -0.3, 0.5, 0.01),
+            Vector4::new(0.2, 1.1, -0.4, 0.02),
+            Vector4::new(-0.6, 0.25, 0.9, 0.5),
+            Vector4::new(3., -2., 7., 1.5),
+        );
+
+        for count in 0..12 {
+            let src: Vec<Vector4> = vector3s(count).iter().map(|v| Vector4::new(v.x, v.y, v.z, v.x - v.y)).collect();
+            let mut dst = vec![Vector4::zero(); count];
+            transform_vec4s(&m, &src, &mut dst);
+
+            for (s, d) in src.iter().zip(&dst) {
+                assert_close(&d.to_array(), &(m * *s).to_array(), &term_scale(&m, *s).to_array());
+            }
+
+            let mut in_place = src.clone();
+            transform_vec4s_in_place(&m, &mut in_place);
+            assert_eq!(in_place, dst);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatched_lengths_panic() {
+        transform_points(&Matrix4x4::identity(), &vector3s(5), &mut [Vector3::zero(); 4]);
+    }
+}
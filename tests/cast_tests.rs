@@ -0,0 +1,71 @@
+mod tests {
+    use division_math::cast::{as_mut_slice, as_slice, from_mut_slice, from_slice};
+    use division_math::{f16, Color, DVector2, HalfVector2, IVector3, Matrix4x4, Vector3, Vector4};
+
+    #[test]
+    fn vectors_as_scalars() {
+        let points = [Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.)];
+
+        assert_eq!(as_slice(&points), &[1., 2., 3., 4., 5., 6.]);
+        assert_eq!(as_slice(&[IVector3::new(-1, 0, 1)]), &[-1, 0, 1]);
+        assert_eq!(as_slice(&[DVector2::new(0.5, 0.25)]), &[0.5, 0.25]);
+        assert_eq!(as_slice(&[Color::new(0.1, 0.2, 0.3, 0.4)]), &[0.1, 0.2, 0.3, 0.4]);
+        assert_eq!(as_slice::<Vector3>(&[]).len(), 0);
+    }
+
+    #[test]
+    fn scalars_as_vectors() {
+        let scalars = [1., 2., 3., 4., 5., 6., 7., 8.];
+
+        assert_eq!(from_slice::<Vector4>(&scalars), &[Vector4::new(1., 2., 3., 4.), Vector4::new(5., 6., 7., 8.)]);
+        assert_eq!(from_slice::<Vector3>(&scalars[..6]), &[Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.)]);
+
+        let halves = [f16::ONE, f16::ZERO];
+        assert_eq!(from_slice::<HalfVector2>(&halves), &[HalfVector2::new(f16::ONE, f16::ZERO)]);
+    }
+
+    #[test]
+    fn matrix_as_column_major_scalars() {
+        let m = Matrix4x4::translation(Vector3::new(7., 8., 9.));
+        let scalars = as_slice(std::slice::from_ref(&m));
+
+        assert_eq!(&scalars[12..], &[7., 8., 9., 1.]);
+        assert_eq!(from_slice::<Matrix4x4>(scalars), &[m]);
+    }
+
+    #[test]
+    fn mutable_views() {
+        let mut points = [Vector3::zero(); 2];
+        as_mut_slice(&mut points)[4] = 5.;
+        assert_eq!(points[1].y, 5.);
+
+        let mut scalars = [0.; 4];
+        from_mut_slice::<Vector4>(&mut scalars)[0].w = 2.;
+        assert_eq!(scalars, [0., 0., 0., 2.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn partial_vector_panics() {
+        from_slice::<Vector3>(&[1., 2., 3., 4.]);
+    }
+
+    #[cfg(feature = "bytemuck")]
+    #[test]
+    fn bytemuck_casts() {
+        use division_math::{BVector3, Vector3A, Vector3x4};
+
+        let points = [Vector3::new(1., 2., 3.)];
+        let bytes: &[u8] = bytemuck::cast_slice(&points);
+        assert_eq!(bytes.len(), 12);
+        assert_eq!(&bytes[4..8], &2f32.to_ne_bytes());
+
+        let m: Matrix4x4 = bytemuck::cast([1f32; 16]);
+        assert_eq!(m, Matrix4x4::all(1.));
+
+        let wide: Vector3x4 = bytemuck::Zeroable::zeroed();
+        assert_eq!(wide, Vector3x4::zero());
+        assert_eq!(<Vector3A as bytemuck::Zeroable>::zeroed(), Vector3A::zero());
+        assert_eq!(<BVector3 as bytemuck::Zeroable>::zeroed(), BVector3::new(false, false, false));
+    }
+}
@@ -0,0 +1,159 @@
+mod tests {
+    use division_math::{approx, lerp, linear_to_srgb, srgb_to_linear, BlendMode, Color, Vector3, Vector4};
+
+    fn approx_eps(x: f32, y: f32, eps: f32) -> bool {
+        (x - y).abs() <= eps
+    }
+
+    fn approx_vec3(x: Vector3, y: Vector3, eps: f32) -> bool {
+        approx_eps(x.x, y.x, eps) && approx_eps(x.y, y.y, eps) && approx_eps(x.z, y.z, eps)
+    }
+
+    fn approx_color(x: Color, y: Color) -> bool {
+        approx_vec3(x.rgb_vec(), y.rgb_vec(), 1e-5) && approx_eps(x.a, y.a, 1e-5)
+    }
+
+    #[test]
+    fn srgb_transfer_round_trip() {
+        for i in 0..=255 {
+            let c = i as f32 / 255.;
+            assert!(approx_eps(linear_to_srgb(srgb_to_linear(c)), c, 1e-5));
+        }
+    }
+
+    #[test]
+    fn srgb_transfer_known_values() {
+        assert_eq!(srgb_to_linear(0.), 0.);
+        assert!(approx(srgb_to_linear(1.), 1.));
+        assert!(approx_eps(srgb_to_linear(0.5), 0.21404114, 1e-6));
+        assert!(approx_eps(linear_to_srgb(0.5), 0.7353569, 1e-6));
+    }
+
+    #[test]
+    fn hsv_round_trip() {
+        let c = Color::from_srgb(0.2, 0.6, 0.9, 1.);
+        let hsv = c.to_hsv();
+
+        assert!(approx_color(Color::from_hsv(hsv, 1.), c));
+        assert!(approx_vec3(
+            Color::RED.to_hsv(),
+            Vector3::new(0., 1., 1.),
+            1e-6
+        ));
+        assert!(approx_vec3(
+            Color::BLUE.to_hsv(),
+            Vector3::new(240., 1., 1.),
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn hsl_round_trip() {
+        let c = Color::from_srgb(0.8, 0.3, 0.1, 0.5);
+        let hsl = c.to_hsl();
+
+        assert!(approx_eps(hsl.z, 0.45, 1e-5));
+        assert!(approx_color(Color::from_hsl(hsl, 0.5), c));
+    }
+
+    #[test]
+    fn oklab_known_values() {
+        assert!(approx_vec3(Color::WHITE.to_oklab(), Vector3::new(1., 0., 0.), 1e-4));
+        assert!(approx_vec3(
+            Color::RED.to_oklab(),
+            Vector3::new(0.6279554, 0.22486307, 0.1258463),
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn oklab_and_oklch_round_trip() {
+        let c = Color::new(0.3, 0.5, 0.1, 1.);
+
+        assert!(approx_vec3(
+            Color::from_oklab(c.to_oklab(), 1.).rgb_vec(),
+            c.rgb_vec(),
+            1e-5
+        ));
+        assert!(approx_vec3(
+            Color::from_oklch(c.to_oklch(), 1.).rgb_vec(),
+            c.rgb_vec(),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn xyz_and_lab_known_values() {
+        assert!(approx_vec3(
+            Color::WHITE.to_xyz(),
+            Vector3::new(0.95047, 1., 1.08883),
+            1e-4
+        ));
+        assert!(approx_vec3(Color::WHITE.to_lab(), Vector3::new(100., 0., 0.), 1e-2));
+
+        let c = Color::new(0.1, 0.4, 0.7, 1.);
+        assert!(approx_vec3(
+            Color::from_lab(c.to_lab(), 1.).rgb_vec(),
+            c.rgb_vec(),
+            1e-4
+        ));
+    }
+
+    #[test]
+    fn premultiplied_round_trip() {
+        let c = Color::new(0.5, 0.25, 1., 0.5);
+        let p = c.premultiplied();
+
+        assert_eq!(p, Color::new(0.25, 0.125, 0.5, 0.5));
+        assert_eq!(p.unpremultiplied(), c);
+        assert_eq!(Color::TRANSPARENT.unpremultiplied(), Color::TRANSPARENT);
+    }
+
+    #[test]
+    fn blend_normal_over_opaque() {
+        let src = Color::new(1., 0., 0., 0.25);
+        let dst = Color::BLUE;
+
+        let result = Color::blend(src, dst, BlendMode::Normal);
+        assert!(Color::approx(result, Color::new(0.25, 0., 0.75, 1.)));
+    }
+
+    #[test]
+    fn blend_modes_opaque() {
+        let src = Color::rgb(0.5, 0.2, 0.8);
+        let dst = Color::rgb(0.4, 0.6, 0.3);
+
+        assert!(approx_color(
+            Color::blend(src, dst, BlendMode::Multiply),
+            Color::rgb(0.2, 0.12, 0.24)
+        ));
+        assert!(approx_color(
+            Color::blend(src, dst, BlendMode::Screen),
+            Color::rgb(0.7, 0.68, 0.86)
+        ));
+        assert!(approx_color(
+            Color::blend(src, dst, BlendMode::Darken),
+            Color::rgb(0.4, 0.2, 0.3)
+        ));
+        assert!(approx_color(
+            Color::blend(src, dst, BlendMode::Overlay),
+            Color::rgb(0.4, 0.36, 0.48)
+        ));
+    }
+
+    #[test]
+    fn vector_conversions() {
+        let v = Vector4::new(0.1, 0.2, 0.3, 0.4);
+        let c = Color::from(v);
+
+        assert_eq!(Vector4::from(c), v);
+        assert_eq!(Color::from(Vector3::new(0.1, 0.2, 0.3)).a, 1.);
+        assert_eq!(Vector3::from(c), Vector3::new(0.1, 0.2, 0.3));
+    }
+
+    #[test]
+    fn color_lerp() {
+        let c = lerp(Color::BLACK, Color::WHITE, 0.5);
+        assert!(approx_color(c, Color::new(0.5, 0.5, 0.5, 1.)));
+    }
+}
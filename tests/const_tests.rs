@@ -0,0 +1,70 @@
+mod tests {
+    use division_math::{
+        f16, BVector3, Color, DMatrix4x4, DVector2, DVector4, HalfVector2, HalfVector3, HalfVector4, IVector2,
+        Matrix4x4, UVector3, Vector2, Vector3, Vector3A, Vector4,
+    };
+
+    const UP: Vector3 = Vector3::up();
+    const OFFSET: Matrix4x4 = Matrix4x4::translation(Vector3::new(1., 2., 3.));
+    const HALF_ONE: HalfVector2 = HalfVector2::new(f16::ONE, f16::from_bits(0x3c00));
+    static ORIGIN: Vector4 = Vector4::new(0., 0., 0., 1.);
+    static GREY: Color = Color::rgb(0.5, 0.5, 0.5);
+
+    #[test]
+    fn const_constructors() {
+        assert_eq!(UP, Vector3::Y);
+        assert_eq!(OFFSET * ORIGIN, Vector4::new(1., 2., 3., 1.));
+        assert_eq!(HALF_ONE.x, HALF_ONE.y);
+        assert_eq!(GREY.a, 1.);
+        assert_eq!(Vector3A::ONE, Vector3A::one());
+    }
+
+    #[test]
+    fn unit_axes() {
+        assert_eq!(Vector3::X, Vector3::right());
+        assert_eq!(Vector3::Z, Vector3::forward());
+        assert_eq!(Vector3::NEG_Z, -Vector3::forward());
+        assert_eq!(Vector2::NEG_Y, Vector2::new(0., -1.));
+        assert_eq!(Vector4::W, Vector4::new(0., 0., 0., 1.));
+        assert_eq!(IVector2::NEG_X, IVector2::new(-1, 0));
+        assert_eq!(UVector3::Z, UVector3::new(0, 0, 1));
+        assert_eq!(Vector3A::NEG_Y, Vector3A::new(0., -1., 0.));
+    }
+
+    #[test]
+    fn splatted_constants() {
+        assert_eq!(Vector3::ZERO, Vector3::zero());
+        assert_eq!(Vector4::ONE, Vector4::one());
+        assert_eq!(IVector2::NEG_ONE, IVector2::all(-1));
+        assert_eq!(UVector3::MAX, UVector3::all(u32::MAX));
+        assert_eq!(Vector2::MIN, Vector2::all(f32::MIN));
+        assert_eq!(Vector3::INFINITY, Vector3::all(f32::INFINITY));
+        assert_eq!(DVector2::NEG_INFINITY, DVector2::all(f64::NEG_INFINITY));
+        assert_eq!(Vector4::NAN.is_nan(), division_math::BVector4::TRUE);
+        assert_eq!(BVector3::FALSE, BVector3::new(false, false, false));
+    }
+
+    #[test]
+    fn matrix_constants() {
+        assert_eq!(Matrix4x4::IDENTITY, Matrix4x4::identity());
+        assert_eq!(Matrix4x4::ZERO, Matrix4x4::all(0.));
+        assert_eq!(DMatrix4x4::IDENTITY, DMatrix4x4::identity());
+        assert!(Matrix4x4::NAN[2].is_nan().all());
+        assert_eq!(Matrix4x4::INFINITY[1], Vector4::INFINITY);
+        assert_eq!(DMatrix4x4::NEG_INFINITY[3], DVector4::NEG_INFINITY);
+    }
+
+    #[test]
+    fn half_vector_constants() {
+        assert_eq!(HalfVector2::ONE, HALF_ONE);
+        assert_eq!(HalfVector3::ZERO, HalfVector3::all(f16::ZERO));
+        assert_eq!(HalfVector2::NEG_ONE.x.to_f32(), -1.);
+        assert_eq!(HalfVector4::MAX.w.to_f32(), 65504.);
+        assert_eq!(HalfVector2::MIN.y.to_f32(), -65504.);
+        assert!(HalfVector2::NAN.x.to_f32().is_nan());
+        assert_eq!(HalfVector3::INFINITY.z.to_f32(), f32::INFINITY);
+        assert_eq!(HalfVector2::NEG_INFINITY.y.to_f32(), f32::NEG_INFINITY);
+        assert_eq!(Vector3::from(HalfVector3::Z), Vector3::Z);
+        assert_eq!(Vector4::from(HalfVector4::NEG_W), -Vector4::W);
+    }
+}
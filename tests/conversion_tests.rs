@@ -0,0 +1,116 @@
+mod tests {
+    use division_math::{
+        f16, BVector2, Color, DMatrix4x4, DVector4, HalfVector2, IVector3, Matrix4x4, UVector2, Vector2, Vector3,
+        Vector3A, Vector4,
+    };
+
+    #[test]
+    fn vector_arrays_and_tuples() {
+        let v = Vector3::new(1., 2., 3.);
+
+        assert_eq!(v.to_array(), [1., 2., 3.]);
+        assert_eq!(Vector3::from([1., 2., 3.]), v);
+        assert_eq!(<[f32; 3]>::from(v), [1., 2., 3.]);
+        assert_eq!(<(f32, f32, f32)>::from(v), (1., 2., 3.));
+        assert_eq!(Vector3::from((1., 2., 3.)), v);
+        assert_eq!(v.as_ref(), &[1., 2., 3.]);
+
+        assert_eq!(IVector3::from([-1, 0, 1]).to_array(), [-1, 0, 1]);
+        assert_eq!(<(u32, u32)>::from(UVector2::new(4, 5)), (4, 5));
+        assert_eq!(DVector4::from_array([0.5; 4]), DVector4::all(0.5));
+        assert_eq!(BVector2::from([true, false]), BVector2::new(true, false));
+        assert_eq!(HalfVector2::from([f16::ONE, f16::ZERO]).to_array(), [f16::ONE, f16::ZERO]);
+        assert_eq!(Vector3A::from((1., 2., 3.)).to_array(), [1., 2., 3.]);
+        assert_eq!(Color::from([0.1, 0.2, 0.3, 0.4]), Color::new(0.1, 0.2, 0.3, 0.4));
+        assert_eq!(Color::WHITE.as_ref(), &[1.; 4]);
+    }
+
+    #[test]
+    fn vector_as_mut() {
+        let mut v = Vector4::zero();
+        v.as_mut()[3] = 2.;
+        assert_eq!(v, Vector4::W * 2.);
+
+        let mut a = Vector3A::zero();
+        AsMut::<[f32; 3]>::as_mut(&mut a)[1] = 5.;
+        assert_eq!(a, Vector3A::new(0., 5., 0.));
+    }
+
+    #[test]
+    fn vector_slices() {
+        let scalars = [1., 2., 3., 4., 5.];
+        assert_eq!(Vector2::from_slice(&scalars), Vector2::new(1., 2.));
+        assert_eq!(Vector4::from_slice(&scalars[1..]), Vector4::new(2., 3., 4., 5.));
+
+        let mut out = [0.; 5];
+        Vector3::new(7., 8., 9.).write_to_slice(&mut out[2..]);
+        assert_eq!(out, [0., 0., 7., 8., 9.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn short_slice_panics() {
+        Vector3::from_slice(&[1., 2.]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn short_output_slice_panics() {
+        Vector4::one().write_to_slice(&mut [0.; 3]);
+    }
+
+    #[test]
+    fn matrix_column_major_arrays() {
+        let m = Matrix4x4::from_columns(
+            Vector4::new(1., 2., 3., 4.),
+            Vector4::new(5., 6., 7., 8.),
+            Vector4::new(9., 10., 11., 12.),
+            Vector4::new(13., 14., 15., 16.),
+        );
+        let flat: [f32; 16] = core::array::from_fn(|i| (i + 1) as f32);
+        let nested = [[1., 2., 3., 4.], [5., 6., 7., 8.], [9., 10., 11., 12.], [13., 14., 15., 16.]];
+
+        assert_eq!(m.to_cols_array(), flat);
+        assert_eq!(m.to_cols_array_2d(), nested);
+        assert_eq!(Matrix4x4::from_cols_array(flat), m);
+        assert_eq!(Matrix4x4::from_cols_array_2d(nested), m);
+        assert_eq!(Matrix4x4::from(flat), m);
+        assert_eq!(<[[f32; 4]; 4]>::from(m), nested);
+        assert_eq!(m.as_ref(), &flat);
+
+        let mut out = [0.; 17];
+        m.write_cols_to_slice(&mut out[1..]);
+        assert_eq!(Matrix4x4::from_cols_slice(&out[1..]), m);
+    }
+
+    #[test]
+    fn matrix_row_major() {
+        let m = Matrix4x4::from_rows(
+            Vector4::new(1., 5., 9., 13.),
+            Vector4::new(2., 6., 10., 14.),
+            Vector4::new(3., 7., 11., 15.),
+            Vector4::new(4., 8., 12., 16.),
+        );
+        assert_eq!(m.to_cols_array(), core::array::from_fn(|i| (i + 1) as f32));
+
+        let rows = [1., 5., 9., 13., 2., 6., 10., 14., 3., 7., 11., 15., 4., 8., 12., 16.];
+        assert_eq!(m.to_rows_array(), rows);
+        assert_eq!(m.to_rows_array_2d()[1], [2., 6., 10., 14.]);
+        assert_eq!(Matrix4x4::from_rows_array(rows), m);
+
+        // Row-major data is what `m * v` reads row by row.
+        let v = Vector4::new(0.5, -1., 2., 1.);
+        let r = m.to_rows_array_2d();
+        let dot = |row: [f32; 4]| row[0] * v.x + row[1] * v.y + row[2] * v.z + row[3] * v.w;
+        assert_eq!(m * v, Vector4::new(dot(r[0]), dot(r[1]), dot(r[2]), dot(r[3])));
+    }
+
+    #[test]
+    fn dmatrix_arrays() {
+        let flat: [f64; 16] = core::array::from_fn(|i| i as f64);
+        let m = DMatrix4x4::from_cols_array(flat);
+
+        assert_eq!(m[1], DVector4::new(4., 5., 6., 7.));
+        assert_eq!(DMatrix4x4::from_rows_array(m.to_rows_array()), m);
+    }
+}
@@ -0,0 +1,128 @@
+mod tests {
+    use division_math::{
+        active_simd_backend, force_simd_backend, transform_points, transform_points_perspective,
+        transform_vec4s, transform_vectors_in_place, Matrix4x4, SimdBackend, Vector3, Vector4,
+    };
+    use std::sync::Mutex;
+
+    // The forced backend is process wide, so tests touching it take turns.
+    static BACKEND_LOCK: Mutex<()> = Mutex::new(());
+
+    fn vector3s(count: usize) -> Vec<Vector3> {
+        (0..count)
+            .map(|i| {
+                let i = i as f32;
+                Vector3::new(
+                    (i * 0.37).sin() * 20.,
+                    (i * 0.11).cos() * 5. - 1.,
+                    i * 0.5 - 8.,
+                )
+            })
+            .collect()
+    }
+
+    fn run_all(
+        src: &[Vector3],
+        src4: &[Vector4],
+    ) -> (Vec<Vector3>, Vec<Vector3>, Vec<Vector3>, Vec<Vector4>) {
+        let m = Matrix4x4::from_columns(
+            Vector4::new(0.8, -0.3, 0.5, 0.01),
+            Vector4::new(0.2, 1.1, -0.4, 0.02),
+            Vector4::new(-0.6, 0.25, 0.9, 0.5),
+            Vector4::new(3., -2., 7., 1.5),
+        );
+        let mut points = vec![Vector3::zero(); src.len()];
+        let mut projected = vec![Vector3::zero(); src.len()];
+        let mut vectors = src.to_vec();
+        let mut vec4s = vec![Vector4::zero(); src4.len()];
+
+        transform_points(&m, src, &mut points);
+        transform_points_perspective(&m, src, &mut projected);
+        transform_vectors_in_place(&m, &mut vectors);
+        transform_vec4s(&m, src4, &mut vec4s);
+
+        (points, projected, vectors, vec4s)
+    }
+
+    // Fused multiply-adds round once per step, so the error is bounded by the magnitude of
+    // the terms rather than of the result.
+    fn close(a: f32, b: f32, scale: f32) -> bool {
+        (a - b).abs() <= 8. * f32::EPSILON * scale
+    }
+
+    #[test]
+    fn backends_match_scalar() {
+        let _lock = BACKEND_LOCK.lock().unwrap();
+        let src = vector3s(131);
+        let src4: Vec<Vector4> = src
+            .iter()
+            .map(|v| Vector4::new(v.x, v.y, v.z, v.y * 0.5))
+            .collect();
+
+        force_simd_backend(Some(SimdBackend::Scalar));
+        let expected = run_all(&src, &src4);
+
+        for backend in SimdBackend::ALL.into_iter().filter(|b| b.is_supported()) {
+            force_simd_backend(Some(backend));
+            let actual = run_all(&src, &src4);
+
+            if backend == SimdBackend::Avx2Fma {
+                let flatten3 =
+                    |v: &[Vector3]| v.iter().flat_map(|v| [v.x, v.y, v.z]).collect::<Vec<_>>();
+                let flatten4 = |v: &[Vector4]| {
+                    v.iter()
+                        .flat_map(|v| [v.x, v.y, v.z, v.w])
+                        .collect::<Vec<_>>()
+                };
+                let pairs = [
+                    (flatten3(&actual.0), flatten3(&expected.0)),
+                    (flatten3(&actual.1), flatten3(&expected.1)),
+                    (flatten3(&actual.2), flatten3(&expected.2)),
+                    (flatten4(&actual.3), flatten4(&expected.3)),
+                ];
+
+                for (a, e) in pairs {
+                    let scale = e.iter().fold(1f32, |m, x| m.max(x.abs()));
+                    assert!(
+                        a.iter().zip(&e).all(|(a, e)| close(*a, *e, scale)),
+                        "{backend:?} diverged from scalar"
+                    );
+                }
+            } else {
+                assert_eq!(actual, expected, "{backend:?} differs from scalar");
+            }
+        }
+
+        force_simd_backend(None);
+    }
+
+    #[test]
+    fn active_backend_follows_override() {
+        let _lock = BACKEND_LOCK.lock().unwrap();
+
+        force_simd_backend(Some(SimdBackend::Scalar));
+        assert_eq!(active_simd_backend(), SimdBackend::Scalar);
+
+        force_simd_backend(None);
+        let detected = active_simd_backend();
+        assert!(detected.is_supported());
+
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma") {
+            assert_eq!(detected, SimdBackend::Avx2Fma);
+        }
+    }
+
+    // Nothing to check on a CPU that supports every backend.
+    #[test]
+    #[cfg_attr(panic = "abort", ignore = "catching the panic needs unwinding")]
+    fn forcing_unsupported_backend_panics() {
+        let _lock = BACKEND_LOCK.lock().unwrap();
+        let before = active_simd_backend();
+
+        for backend in SimdBackend::ALL.into_iter().filter(|b| !b.is_supported()) {
+            assert!(std::panic::catch_unwind(|| force_simd_backend(Some(backend))).is_err());
+            assert_eq!(active_simd_backend(), before, "{backend:?} was forced anyway");
+        }
+    }
+}
@@ -0,0 +1,74 @@
+mod tests {
+    use division_math::{approx_f64, DMatrix4x4, DVector2, DVector3, DVector4, Matrix4x4, Vector3, Vector4};
+
+    #[test]
+    fn dvector_ops() {
+        let v = DVector3::new(1., 2., 3.);
+
+        assert_eq!(v + DVector3::one(), DVector3::new(2., 3., 4.));
+        assert_eq!(v * 2., DVector3::new(2., 4., 6.));
+        assert_eq!(-v, DVector3::new(-1., -2., -3.));
+        assert_eq!(v[2], 3.);
+        assert_eq!(DVector3::dot(v, v), 14.);
+        assert_eq!(DVector3::cross(DVector3::new(1., 0., 0.), DVector3::new(0., 1., 0.)), DVector3::new(0., 0., 1.));
+
+        let mut a = DVector4::all(1.);
+        a += DVector4::one();
+        a *= 0.5;
+        assert_eq!(a, DVector4::one());
+    }
+
+    #[cfg(feature = "swizzle_full")]
+    #[test]
+    fn dvector_swizzles() {
+        let v = DVector4::new(1., 2., 3., 4.);
+
+        assert_eq!(v.wzyx(), DVector4::new(4., 3., 2., 1.));
+        assert_eq!(v.xz(), DVector2::new(1., 3.));
+        assert_eq!(DVector2::new(5., 6.).yxy(), DVector3::new(6., 5., 6.));
+    }
+
+    #[test]
+    fn dvector_math() {
+        let v = DVector3::lerp(DVector3::zero(), DVector3::all(2.), 0.25);
+        assert!(DVector3::approx(v, DVector3::all(0.5)));
+
+        let n = DVector3::new(3., 4., 0.).normalized();
+        assert!(approx_f64(n.length(), 1.));
+
+        let c = DVector2::new(-1., 5.).clamp(DVector2::zero(), DVector2::one());
+        assert_eq!(c, DVector2::new(0., 1.));
+        assert!(approx_f64(DVector3::all(10.).clamp_length(0., 2.).length(), 2.));
+    }
+
+    #[test]
+    fn dvector_keeps_double_precision() {
+        let origin = DVector3::new(6_378_137., 0., 0.);
+        let moved = origin + DVector3::new(0.001, 0., 0.);
+
+        assert_eq!(moved.x - origin.x, 0.0010000001639127731);
+        assert_eq!((origin.as_f32() + Vector3::new(0.001, 0., 0.)).x, 6_378_137.);
+    }
+
+    #[test]
+    fn precision_conversions() {
+        let v = Vector4::new(0.1, 0.2, 0.3, 0.4);
+        let d = DVector4::from(v);
+
+        assert_eq!(d.x, 0.1f32 as f64);
+        assert_eq!(d.as_f32(), v);
+        assert_eq!(v.as_f64(), d);
+
+        let m = Matrix4x4::scale(Vector3::new(1., 2., 3.));
+        assert_eq!(DMatrix4x4::from(m).as_f32(), m);
+    }
+
+    #[test]
+    fn dmatrix_inverse() {
+        let m = DMatrix4x4::translation(DVector3::new(1e7, -3., 2.)) * DMatrix4x4::scale(DVector3::all(3.));
+        let i = m.inverse();
+
+        assert!(DMatrix4x4::approx(m * i, DMatrix4x4::identity()));
+        assert_eq!((m * DVector4::new(1., 1., 1., 1.)).x, 1e7 + 3.);
+    }
+}
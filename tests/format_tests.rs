@@ -0,0 +1,110 @@
+mod tests {
+    use division_math::{
+        f16, BVector3, Color, DMatrix4x4, HalfVector2, IVector2, Matrix4x4, ParseMathError, UVector4, Vector2,
+        Vector3, Vector3A, Vector3x4, Vector4,
+    };
+
+    #[test]
+    fn defaults() {
+        assert_eq!(Vector3::default(), Vector3::ZERO);
+        assert_eq!(IVector2::default(), IVector2::ZERO);
+        assert_eq!(BVector3::default(), BVector3::FALSE);
+        assert_eq!(Vector3A::default(), Vector3A::ZERO);
+        assert_eq!(HalfVector2::default(), HalfVector2::new(f16::ZERO, f16::ZERO));
+        assert_eq!(Color::default(), Color::TRANSPARENT);
+        assert_eq!(Vector3x4::default(), Vector3x4::splat(Vector3::ZERO));
+        assert_eq!(Matrix4x4::default(), Matrix4x4::IDENTITY);
+        assert_eq!(DMatrix4x4::default(), DMatrix4x4::IDENTITY);
+    }
+
+    #[test]
+    fn vector_display() {
+        assert_eq!(Vector2::new(1., -2.5).to_string(), "[1, -2.5]");
+        assert_eq!(format!("{:.3}", Vector3::new(1., 2., 1. / 3.)), "[1.000, 2.000, 0.333]");
+        assert_eq!(format!("{:+.1}", Vector3A::new(1., -2., 0.)), "[+1.0, -2.0, +0.0]");
+        assert_eq!(UVector4::new(1, 2, 3, 4).to_string(), "[1, 2, 3, 4]");
+        assert_eq!(BVector3::new(true, false, true).to_string(), "[true, false, true]");
+        assert_eq!(format!("{:.2}", HalfVector2::new(f16::ONE, f16::from_f32(0.5))), "[1.00, 0.50]");
+        assert_eq!(format!("{:.1}", Color::RED), "[1.0, 0.0, 0.0, 1.0]");
+    }
+
+    #[test]
+    fn matrix_display() {
+        let m = Matrix4x4::from_rows(
+            Vector4::new(1., 0., 0., 10.),
+            Vector4::new(0., -2.5, 0., 200.),
+            Vector4::new(0., 0., 1., -3.),
+            Vector4::new(0., 0., 0., 1.),
+        );
+        assert_eq!(
+            m.to_string(),
+            "[[1, 0, 0, 10], [0, -2.5, 0, 200], [0, 0, 1, -3], [0, 0, 0, 1]]"
+        );
+        assert_eq!(
+            format!("{m:#.1}"),
+            "[[1.0,  0.0, 0.0,  10.0],\n [0.0, -2.5, 0.0, 200.0],\n [0.0,  0.0, 1.0,  -3.0],\n [0.0,  0.0, 0.0,   1.0]]"
+        );
+        assert_eq!(
+            format!("{:#}", Matrix4x4::IDENTITY),
+            "[[1, 0, 0, 0],\n [0, 1, 0, 0],\n [0, 0, 1, 0],\n [0, 0, 0, 1]]"
+        );
+    }
+
+    #[test]
+    fn round_trips() {
+        let v = Vector4::new(0.1, -7., 1e-8, 3.25);
+        assert_eq!(v.to_string().parse::<Vector4>(), Ok(v));
+        assert_eq!(format!("{:.2}", v).parse::<Vector4>(), Ok(Vector4::new(0.1, -7., 0., 3.25)));
+        assert_eq!("[-3, 4]".parse::<IVector2>(), Ok(IVector2::new(-3, 4)));
+        assert_eq!("[true,false, true]".parse::<BVector3>(), Ok(BVector3::new(true, false, true)));
+        assert_eq!(" [ 0.5 , 1 ] ".parse::<HalfVector2>(), Ok(HalfVector2::new(f16::from_f32(0.5), f16::ONE)));
+        assert_eq!(Color::BLUE.to_string().parse::<Color>(), Ok(Color::BLUE));
+        assert_eq!("1.5".parse::<f16>(), Ok(f16::from_f32(1.5)));
+
+        let m = Matrix4x4::from_cols_array(core::array::from_fn(|i| i as f32 * -1.25 + 10.));
+        assert_eq!(m.to_string().parse::<Matrix4x4>(), Ok(m));
+        assert_eq!(format!("{m:#}").parse::<Matrix4x4>(), Ok(m));
+        let d = DMatrix4x4::from_cols_array(core::array::from_fn(|i| i as f64 * 0.5));
+        assert_eq!(format!("{d:#.3}").parse::<DMatrix4x4>(), Ok(d));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("1, 2".parse::<Vector2>(), Err(ParseMathError::InvalidFormat));
+        assert_eq!("[1, 2".parse::<Vector2>(), Err(ParseMathError::InvalidFormat));
+        assert_eq!(
+            "[1, 2]".parse::<Vector3>(),
+            Err(ParseMathError::WrongLength { expected: 3, found: 2 })
+        );
+        assert_eq!(
+            "[1, x, 3]".parse::<Vector3>(),
+            Err(ParseMathError::InvalidComponent { index: 1 })
+        );
+        assert_eq!(
+            "[1, -2]".parse::<UVector4>(),
+            Err(ParseMathError::WrongLength { expected: 4, found: 2 })
+        );
+
+        assert_eq!(
+            "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0]]".parse::<Matrix4x4>(),
+            Err(ParseMathError::WrongLength { expected: 4, found: 3 })
+        );
+        assert_eq!(
+            "[[1, 0, 0, 0] [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, 0, 1]]".parse::<Matrix4x4>(),
+            Err(ParseMathError::InvalidFormat)
+        );
+        assert_eq!(
+            "[[1, 0, 0, 0], [0, 1, 0, 0], [0, 0, 1, 0], [0, 0, a, 1]]".parse::<Matrix4x4>(),
+            Err(ParseMathError::InvalidComponent { index: 14 })
+        );
+        assert_eq!(
+            "[[1, 0, 0, 0], [0, 1, 0], [0, 0, 1, 0], [0, 0, 0, 1]]".parse::<Matrix4x4>(),
+            Err(ParseMathError::WrongLength { expected: 4, found: 3 })
+        );
+
+        assert_eq!(
+            ParseMathError::WrongLength { expected: 3, found: 2 }.to_string(),
+            "expected 3 components, found 2"
+        );
+    }
+}
@@ -0,0 +1,139 @@
+mod tests {
+    use division_math::{
+        f16, f16_to_f32_slice, f32_to_f16_slice, HalfVector2, HalfVector3, HalfVector4, Vector2,
+        Vector3, Vector4,
+    };
+
+    #[test]
+    fn every_half_round_trips_through_f32() {
+        for bits in 0..=u16::MAX {
+            let h = f16::from_bits(bits);
+            let back = f16::from_f32(h.to_f32());
+
+            if h.is_nan() {
+                assert!(back.is_nan());
+            } else {
+                assert_eq!(back.to_bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn known_values() {
+        assert_eq!(f16::from_f32(1.).to_bits(), 0x3c00);
+        assert_eq!(f16::from_f32(-2.).to_bits(), 0xc000);
+        assert_eq!(f16::from_f32(65504.).to_bits(), 0x7bff);
+        assert_eq!(f16::from_f32(-0.).to_bits(), 0x8000);
+        assert_eq!(f16::MIN_POSITIVE.to_f32(), 2f32.powi(-14));
+        assert_eq!(f16::EPSILON.to_f32(), 2f32.powi(-10));
+        assert_eq!(f16::from_bits(0x0001).to_f32(), 2f32.powi(-24));
+        assert!(f16::from_f32(f32::NAN).is_nan());
+        assert_eq!(f16::from_f32(f32::INFINITY), f16::INFINITY);
+        assert_eq!(f16::from_f32(f32::NEG_INFINITY), f16::NEG_INFINITY);
+    }
+
+    #[test]
+    fn rounds_to_nearest_even() {
+        let ulp = 2f32.powi(-10);
+
+        assert_eq!(f16::from_f32(1. + ulp * 0.5).to_bits(), 0x3c00);
+        assert_eq!(f16::from_f32(1. + ulp * 1.5).to_bits(), 0x3c02);
+        assert_eq!(f16::from_f32(1. + ulp * 0.5001).to_bits(), 0x3c01);
+
+        assert_eq!(f16::from_f32(65519.), f16::MAX);
+        assert_eq!(f16::from_f32(65520.), f16::INFINITY);
+
+        assert_eq!(f16::from_f32(2f32.powi(-25)).to_bits(), 0);
+        assert_eq!(f16::from_f32(2f32.powi(-25) * 1.01).to_bits(), 1);
+        assert_eq!(f16::from_f32(2f32.powi(-24) * 1.5).to_bits(), 2);
+        assert_eq!(f16::from_f32(2f32.powi(-14) * (1. - 2f32.powi(-12))).to_bits(), 0x0400);
+    }
+
+    #[test]
+    fn matches_hardware_conversion() {
+        #[cfg(target_arch = "x86_64")]
+        if is_x86_feature_detected!("f16c") {
+            use std::arch::x86_64::{_mm_cvtps_ph, _mm_cvtsi128_si32, _mm_set1_ps, _MM_FROUND_TO_NEAREST_INT};
+
+            for _ in 0..100000 {
+                let v = f32::from_bits(rand::random::<u32>());
+                let expected = unsafe {
+                    _mm_cvtsi128_si32(_mm_cvtps_ph::<_MM_FROUND_TO_NEAREST_INT>(_mm_set1_ps(v))) as u16
+                };
+
+                assert_eq!(f16::from_f32(v).to_bits(), expected, "{v:e}");
+            }
+        }
+    }
+
+    #[test]
+    fn slice_conversion_matches_scalar() {
+        for len in 0..=9 {
+            let src = (0..len)
+                .map(|i| (i as f32 - 4.) * 1234.567)
+                .collect::<Vec<f32>>();
+            let mut half = vec![f16::ZERO; len];
+            let mut back = vec![0f32; len];
+
+            f32_to_f16_slice(&src, &mut half);
+            f16_to_f32_slice(&half, &mut back);
+
+            for i in 0..len {
+                assert_eq!(half[i].to_bits(), f16::from_f32(src[i]).to_bits());
+                assert_eq!(back[i], half[i].to_f32());
+            }
+        }
+    }
+
+    // On x86_64 with F16C the slices run through the hardware path for every full group of
+    // four, so random bit patterns (NaNs, subnormals, overflow) check it against the scalar
+    // conversion.
+    #[test]
+    fn simd_prefix_matches_scalar() {
+        for len in [4, 8, 13, 64] {
+            for _ in 0..1000 {
+                let src = (0..len)
+                    .map(|_| f32::from_bits(rand::random::<u32>()))
+                    .collect::<Vec<f32>>();
+                let halves = (0..len)
+                    .map(|_| f16::from_bits(rand::random::<u16>()))
+                    .collect::<Vec<f16>>();
+                let mut half = vec![f16::ZERO; len];
+                let mut back = vec![0f32; len];
+
+                f32_to_f16_slice(&src, &mut half);
+                f16_to_f32_slice(&halves, &mut back);
+
+                for i in 0..len {
+                    assert_eq!(half[i].to_bits(), f16::from_f32(src[i]).to_bits(), "{:e}", src[i]);
+                    assert_eq!(back[i].to_bits(), halves[i].to_f32().to_bits(), "{:#06x}", halves[i].to_bits());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn half_vectors_convert() {
+        let v = Vector3::new(0.5, -1.25, 1024.);
+        let h = HalfVector3::from(v);
+
+        assert_eq!(h.y, f16::from_f32(-1.25));
+        assert_eq!(Vector3::from(h), v);
+        assert_eq!(Vector2::from(HalfVector2::from(Vector2::one())), Vector2::one());
+        assert_eq!(std::mem::size_of::<HalfVector4>(), 8);
+    }
+
+    #[test]
+    fn half_vector_slices() {
+        let src = (0..7)
+            .map(|i| Vector4::all(i as f32 * 0.25))
+            .collect::<Vec<Vector4>>();
+        let mut half = vec![HalfVector4::from(Vector4::zero()); src.len()];
+        let mut back = vec![Vector4::zero(); src.len()];
+
+        HalfVector4::from_vector_slice(&src, &mut half);
+        HalfVector4::to_vector_slice(&half, &mut back);
+
+        assert_eq!(back, src);
+    }
+}
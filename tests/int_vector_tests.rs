@@ -0,0 +1,97 @@
+mod tests {
+    use division_math::{DVector3, IVector2, IVector3, RoundingMode, UVector2, UVector3, Vector2, Vector3};
+    use std::collections::{BTreeSet, HashSet};
+
+    #[test]
+    fn int_vector_ops() {
+        let v = IVector3::new(1, -2, 3);
+
+        assert_eq!(v + IVector3::one(), IVector3::new(2, -1, 4));
+        assert_eq!(v * 2, IVector3::new(2, -4, 6));
+        assert_eq!(v / IVector3::new(1, 2, 2), IVector3::new(1, -1, 1));
+        assert_eq!(-v, IVector3::new(-1, 2, -3));
+        assert_eq!(IVector3::dot(v, v), 14);
+        assert_eq!(v[1], -2);
+
+        let mut a = UVector2::new(7, 8);
+        a %= 3;
+        a -= UVector2::one();
+        assert_eq!(a, UVector2::new(0, 1));
+    }
+
+    #[test]
+    fn int_vector_bitwise_and_shifts() {
+        let v = UVector3::new(0b1100, 0b1010, 0xff);
+
+        assert_eq!(v & UVector3::all(0b1000), UVector3::new(0b1000, 0b1000, 0b1000));
+        assert_eq!(v | 1, UVector3::new(0b1101, 0b1011, 0xff));
+        assert_eq!(v ^ v, UVector3::zero());
+        assert_eq!(!UVector2::zero(), UVector2::all(u32::MAX));
+        assert_eq!(v << 1, UVector3::new(0b11000, 0b10100, 0x1fe));
+        assert_eq!(v >> UVector3::new(2, 1, 4), UVector3::new(0b11, 0b101, 0xf));
+        assert_eq!(IVector2::new(-8, 8) >> 1, IVector2::new(-4, 4));
+
+        let mut m = IVector2::new(1, 2);
+        m <<= 2;
+        m |= IVector2::new(1, 0);
+        assert_eq!(m, IVector2::new(5, 8));
+    }
+
+    #[test]
+    fn wrapping_saturating_checked() {
+        let max = IVector2::new(i32::MAX, 0);
+
+        assert_eq!(max.wrapping_add(IVector2::one()), IVector2::new(i32::MIN, 1));
+        assert_eq!(max.saturating_add(IVector2::one()), IVector2::new(i32::MAX, 1));
+        assert_eq!(UVector2::zero().saturating_sub(UVector2::one()), UVector2::zero());
+        assert_eq!(UVector2::zero().wrapping_sub(UVector2::one()), UVector2::all(u32::MAX));
+        assert_eq!(max.checked_add(IVector2::one()), None);
+        assert_eq!(IVector2::one().checked_mul(IVector2::all(3)), Some(IVector2::all(3)));
+        assert_eq!(IVector2::one().checked_div(IVector2::new(1, 0)), None);
+    }
+
+    #[test]
+    fn min_max_and_ordering() {
+        let a = IVector3::new(1, 5, -3);
+        let b = IVector3::new(2, 0, -4);
+
+        assert_eq!(a.min(b), IVector3::new(1, 0, -4));
+        assert_eq!(a.max(b), IVector3::new(2, 5, -3));
+        assert!(a < b);
+
+        let set = [a, b, a].into_iter().collect::<HashSet<IVector3>>();
+        assert_eq!(set.len(), 2);
+
+        let sorted = [b, a].into_iter().collect::<BTreeSet<IVector3>>();
+        assert_eq!(sorted.into_iter().next(), Some(a));
+    }
+
+    #[test]
+    fn float_conversions() {
+        let v = Vector3::new(-1.5, 0.5, 2.7);
+
+        assert_eq!(v.as_i32(RoundingMode::Floor), IVector3::new(-2, 0, 2));
+        assert_eq!(v.as_i32(RoundingMode::Ceil), IVector3::new(-1, 1, 3));
+        assert_eq!(v.as_i32(RoundingMode::Round), IVector3::new(-2, 1, 3));
+        assert_eq!(v.as_i32(RoundingMode::Truncate), IVector3::new(-1, 0, 2));
+        assert_eq!(v.as_u32(RoundingMode::Floor), UVector3::new(0, 0, 2));
+        assert_eq!(Vector2::new(0.4, 0.6).as_u32(RoundingMode::Round), UVector2::new(0, 1));
+        assert_eq!(DVector3::new(1e12, -1e12, 0.4).as_i32(RoundingMode::Round), IVector3::new(i32::MAX, i32::MIN, 0));
+
+        assert_eq!(IVector3::new(1, -2, 3).as_f32(), Vector3::new(1., -2., 3.));
+        assert_eq!(DVector3::from(IVector3::new(i32::MAX, 0, 0)).x, i32::MAX as f64);
+        assert_eq!(IVector2::new(-1, 2).as_u32(), UVector2::new(u32::MAX, 2));
+    }
+
+    #[cfg(feature = "swizzle_full")]
+    #[test]
+    fn int_vector_swizzles() {
+        use division_math::IVector4;
+
+        let v = IVector4::new(1, 2, 3, 4);
+
+        assert_eq!(v.wzyx(), IVector4::new(4, 3, 2, 1));
+        assert_eq!(UVector2::new(1, 2).yx(), UVector2::new(2, 1));
+        assert_eq!(IVector2::from((3, 4)).xxy(), IVector3::new(3, 3, 4));
+    }
+}
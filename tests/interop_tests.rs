@@ -0,0 +1,80 @@
+#![cfg(any(feature = "mint", feature = "glam", feature = "nalgebra"))]
+
+mod tests {
+    use division_math::{DMatrix4x4, Matrix4x4};
+
+    #[cfg(feature = "mint")]
+    #[test]
+    fn mint_conversions() {
+        use division_math::{IVector3, Vector3, Vector3A};
+        let matrix = Matrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f32));
+        let dmatrix = DMatrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f64));
+
+        let v: mint::Vector3<f32> = Vector3::new(1., 2., 3.).into();
+        assert_eq!((v.x, v.y, v.z), (1., 2., 3.));
+        assert_eq!(Vector3::from(v), Vector3::new(1., 2., 3.));
+        assert_eq!(IVector3::from(mint::Vector3 { x: -1, y: 0, z: 1 }), IVector3::new(-1, 0, 1));
+        assert_eq!(Vector3A::from(mint::Vector3::from(Vector3A::new(1., 2., 3.))), Vector3A::new(1., 2., 3.));
+
+        let m: mint::ColumnMatrix4<f32> = matrix.into();
+        assert_eq!(m.y, mint::Vector4 { x: 5., y: 6., z: 7., w: 8. });
+        assert_eq!(m.w.x, 13.);
+        assert_eq!(Matrix4x4::from(m), matrix);
+
+        let d: mint::ColumnMatrix4<f64> = dmatrix.into();
+        assert_eq!(d.z.w, 12.);
+        assert_eq!(DMatrix4x4::from(d), dmatrix);
+    }
+
+    #[cfg(feature = "glam")]
+    #[test]
+    fn glam_conversions() {
+        use division_math::{DVector4, UVector2, Vector2, Vector3A, Vector4};
+        let matrix = Matrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f32));
+        let dmatrix = DMatrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f64));
+
+        assert_eq!(glam::Vec2::from(Vector2::new(1., 2.)), glam::Vec2::new(1., 2.));
+        assert_eq!(UVector2::from(glam::UVec2::new(3, 4)), UVector2::new(3, 4));
+        assert_eq!(glam::Vec3A::from(Vector3A::new(1., 2., 3.)), glam::Vec3A::new(1., 2., 3.));
+        assert_eq!(DVector4::from(glam::DVec4::new(1., 2., 3., 4.)), DVector4::new(1., 2., 3., 4.));
+
+        let m = glam::Mat4::from(matrix);
+        assert_eq!(m.col(1), glam::Vec4::new(5., 6., 7., 8.));
+        assert_eq!(m.to_cols_array(), std::array::from_fn(|i| (i + 1) as f32));
+        assert_eq!(Matrix4x4::from(m), matrix);
+
+        let v = Vector4::new(0.5, -1., 2., 1.);
+        assert_eq!(Vector4::from(m * glam::Vec4::from(v)), matrix * v);
+
+        let d = glam::DMat4::from(dmatrix);
+        assert_eq!(d.to_cols_array(), std::array::from_fn(|i| (i + 1) as f64));
+        assert_eq!(DMatrix4x4::from(d), dmatrix);
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn nalgebra_conversions() {
+        use division_math::{IVector3, Vector3, Vector4};
+        let matrix = Matrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f32));
+        let dmatrix = DMatrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f64));
+
+        assert_eq!(nalgebra::Vector3::from(Vector3::new(1., 2., 3.)), nalgebra::Vector3::new(1., 2., 3.));
+        assert_eq!(IVector3::from(nalgebra::Vector3::new(-1, 0, 1)), IVector3::new(-1, 0, 1));
+
+        let m = nalgebra::Matrix4::from(matrix);
+        for column in 0..4 {
+            for row in 0..4 {
+                assert_eq!(m[(row, column)], matrix[column][row]);
+            }
+        }
+        assert_eq!(m.as_slice(), std::array::from_fn::<f32, 16, _>(|i| (i + 1) as f32));
+        assert_eq!(Matrix4x4::from(m), matrix);
+
+        let v = Vector4::new(0.5, -1., 2., 1.);
+        assert_eq!(Vector4::from(m * nalgebra::Vector4::from(v)), matrix * v);
+
+        let d = nalgebra::Matrix4::from(dmatrix);
+        assert_eq!(d[(3, 2)], 12.);
+        assert_eq!(DMatrix4x4::from(d), dmatrix);
+    }
+}
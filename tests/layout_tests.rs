@@ -0,0 +1,262 @@
+mod tests {
+    use division_math::layout::{Align16, Align32, GpuStruct, GpuType, LayoutRules, LayoutWriter};
+    use division_math::{BVector2, DVector3, Matrix4x4, UVector3, Vector2, Vector3, Vector4};
+
+    // The example block from the std140 section of the OpenGL spec. The crate has no mat2x3 or
+    // mat3, they are written as what the spec stores them as, arrays of vec3 columns:
+    //
+    //     layout(std140) uniform Example {
+    //         float a;
+    //         vec2 b;
+    //         vec3 c;
+    //         struct { int d; bvec2 e; } f;
+    //         float g;
+    //         float h[2];
+    //         mat2x3 i;
+    //         struct { uvec3 j; vec2 k; float l[2]; vec2 m; mat3 n[2]; } o[2];
+    //     };
+    struct F {
+        d: i32,
+        e: BVector2,
+    }
+
+    struct O {
+        j: UVector3,
+        k: Vector2,
+        l: [f32; 2],
+        m: Vector2,
+        n: [Vector3; 6],
+    }
+
+    struct Example {
+        a: f32,
+        b: Vector2,
+        c: Vector3,
+        f: F,
+        g: f32,
+        h: [f32; 2],
+        i: [Vector3; 2],
+        o: [O; 2],
+    }
+
+    #[derive(Default, Debug, PartialEq)]
+    struct Offsets {
+        a: usize,
+        b: usize,
+        c: usize,
+        f: usize,
+        g: usize,
+        h: usize,
+        i: usize,
+        o: usize,
+    }
+
+    impl GpuStruct for F {
+        fn write_fields(&self, w: &mut LayoutWriter<'_>) {
+            assert_eq!(w.field(self.d), 0);
+            assert_eq!(w.field(self.e), 8);
+        }
+    }
+
+    impl GpuStruct for O {
+        fn write_fields(&self, w: &mut LayoutWriter<'_>) {
+            let offsets = [
+                w.field(self.j),
+                w.field(self.k),
+                w.array(&self.l),
+                w.field(self.m),
+                w.array(&self.n),
+            ];
+            let expected = match w.rules() {
+                LayoutRules::Std140 => [0, 16, 32, 64, 80],
+                LayoutRules::Std430 => [0, 16, 24, 32, 48],
+            };
+            assert_eq!(offsets, expected);
+        }
+    }
+
+    impl Example {
+        fn offsets(&self, w: &mut LayoutWriter<'_>) -> Offsets {
+            Offsets {
+                a: w.field(self.a),
+                b: w.field(self.b),
+                c: w.field(self.c),
+                f: w.nested(&self.f),
+                g: w.field(self.g),
+                h: w.array(&self.h),
+                i: w.array(&self.i),
+                o: w.struct_array(&self.o),
+            }
+        }
+    }
+
+    impl GpuStruct for Example {
+        fn write_fields(&self, w: &mut LayoutWriter<'_>) {
+            self.offsets(w);
+        }
+    }
+
+    fn example() -> Example {
+        let o = |base: f32| O {
+            j: UVector3::new(1, 2, 3),
+            k: Vector2::all(base),
+            l: [base + 1., base + 2.],
+            m: Vector2::all(base + 3.),
+            n: [Vector3::all(base + 4.); 6],
+        };
+
+        Example {
+            a: 1.,
+            b: Vector2::new(2., 3.),
+            c: Vector3::new(4., 5., 6.),
+            f: F {
+                d: -7,
+                e: BVector2::new(true, false),
+            },
+            g: 8.,
+            h: [9., 10.],
+            i: [Vector3::all(11.), Vector3::all(12.)],
+            o: [o(20.), o(30.)],
+        }
+    }
+
+    fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+        f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+    }
+
+    #[test]
+    fn std140_spec_example() {
+        let value = example();
+        let offsets = value.offsets(&mut LayoutWriter::measure(LayoutRules::Std140));
+        let expected = Offsets {
+            a: 0,
+            b: 8,
+            c: 16,
+            f: 32,
+            g: 48,
+            h: 64,
+            i: 96,
+            o: 128,
+        };
+        assert_eq!(offsets, expected);
+        assert_eq!(LayoutRules::Std140.size_of(&value), 480);
+
+        let mut buffer = [0xff; 512];
+        assert_eq!(LayoutRules::Std140.write(&value, &mut buffer), 480);
+
+        assert_eq!(read_f32(&buffer, 0), 1.);
+        assert_eq!(read_u32(&buffer, 4), 0);
+        assert_eq!(read_f32(&buffer, 12), 3.);
+        assert_eq!(read_f32(&buffer, 24), 6.);
+        assert_eq!(read_u32(&buffer, 32), -7i32 as u32);
+        assert_eq!([read_u32(&buffer, 40), read_u32(&buffer, 44)], [1, 0]);
+        assert_eq!(read_f32(&buffer, 48), 8.);
+        assert_eq!([read_f32(&buffer, 64), read_f32(&buffer, 80)], [9., 10.]);
+        assert_eq!(read_u32(&buffer, 84), 0);
+        assert_eq!([read_f32(&buffer, 96), read_f32(&buffer, 112)], [11., 12.]);
+
+        // o[0] from the spec's table, o[1] one 176 byte stride later.
+        for (base, value) in [(128, 20.), (304, 30.)] {
+            assert_eq!(read_u32(&buffer, base + 8), 3);
+            assert_eq!(read_f32(&buffer, base + 16), value);
+            assert_eq!([read_f32(&buffer, base + 32), read_f32(&buffer, base + 48)], [value + 1., value + 2.]);
+            assert_eq!(read_f32(&buffer, base + 64), value + 3.);
+            assert_eq!(read_f32(&buffer, base + 80), value + 4.);
+            assert_eq!(read_f32(&buffer, base + 160 + 8), value + 4.);
+            assert_eq!(read_u32(&buffer, base + 172), 0);
+        }
+
+        // Nothing past the block is touched.
+        assert!(buffer[480..].iter().all(|&b| b == 0xff));
+    }
+
+    #[test]
+    fn std430_spec_example() {
+        let value = example();
+        let offsets = value.offsets(&mut LayoutWriter::measure(LayoutRules::Std430));
+        let expected = Offsets {
+            a: 0,
+            b: 8,
+            c: 16,
+            f: 32,
+            g: 48,
+            h: 52,
+            i: 64,
+            o: 96,
+        };
+        assert_eq!(offsets, expected);
+        assert_eq!(LayoutRules::Std430.size_of(&value), 384);
+
+        let mut buffer = [0xff; 384];
+        LayoutRules::Std430.write(&value, &mut buffer);
+
+        assert_eq!([read_f32(&buffer, 52), read_f32(&buffer, 56)], [9., 10.]);
+        assert_eq!(read_u32(&buffer, 60), 0);
+        assert_eq!([read_f32(&buffer, 96 + 24), read_f32(&buffer, 96 + 28)], [21., 22.]);
+        assert_eq!([read_f32(&buffer, 240 + 24), read_f32(&buffer, 240 + 28)], [31., 32.]);
+    }
+
+    struct Mixed {
+        transform: Matrix4x4,
+        tint: Vector4,
+        offset: DVector3,
+        scale: f64,
+    }
+
+    impl GpuStruct for Mixed {
+        fn write_fields(&self, w: &mut LayoutWriter<'_>) {
+            assert_eq!(w.field(self.transform), 0);
+            assert_eq!(w.field(self.tint), 64);
+            assert_eq!(w.field(self.offset), 96);
+            assert_eq!(w.field(self.scale), 120);
+        }
+    }
+
+    #[test]
+    fn matrices_and_doubles() {
+        let value = Mixed {
+            transform: Matrix4x4::translation(Vector3::new(1., 2., 3.)),
+            tint: Vector4::ONE,
+            offset: DVector3::new(0.5, 1.5, 2.5),
+            scale: 4.,
+        };
+        let mut buffer = [0; 128];
+
+        assert_eq!(LayoutRules::Std140.size_of(&value), 128);
+        LayoutRules::Std140.write(&value, &mut buffer);
+
+        let expected: Vec<u8> = value.transform.to_cols_array().iter().flat_map(|v| v.to_le_bytes()).collect();
+        assert_eq!(&buffer[..64], &expected[..]);
+        assert_eq!(&buffer[104..112], &1.5f64.to_le_bytes());
+    }
+
+    #[test]
+    fn aligned_wrappers() {
+        assert_eq!(core::mem::size_of::<[Align16<f32>; 2]>(), 32);
+        assert_eq!(core::mem::size_of::<Align16<Vector3>>(), 16);
+        assert_eq!(core::mem::align_of::<Align32<DVector3>>(), 32);
+
+        assert_eq!(<Align16<f32> as GpuType>::ALIGN, 16);
+        assert_eq!(<Align16<f32> as GpuType>::SIZE, 16);
+        assert_eq!(<Align16<Matrix4x4> as GpuType>::SIZE, 64);
+        assert_eq!(<Align32<DVector3> as GpuType>::SIZE, 32);
+
+        // Padded scalars give the std140 array stride even under std430.
+        let mut buffer = [0xff; 32];
+        let mut w = LayoutWriter::new(LayoutRules::Std430, &mut buffer);
+        assert_eq!(w.array(&[Align16(1f32), Align16(2.)]), 0);
+        assert_eq!(w.finish(), 32);
+        assert_eq!(read_f32(&buffer, 16), 2.);
+        assert!(buffer[4..16].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    #[should_panic]
+    fn small_buffer_panics() {
+        LayoutRules::Std140.write(&example(), &mut [0; 479]);
+    }
+}
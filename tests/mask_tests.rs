@@ -0,0 +1,60 @@
+mod tests {
+    use division_math::{BVector2, BVector3, BVector4, DVector2, IVector3, UVector2, Vector3, Vector4};
+
+    #[test]
+    fn vector_compares() {
+        let a = Vector4::new(1., 2., 3., 4.);
+        let b = Vector4::new(4., 2., 1., 5.);
+
+        assert_eq!(a.cmplt(b), BVector4::new(true, false, false, true));
+        assert_eq!(a.cmple(b), BVector4::new(true, true, false, true));
+        assert_eq!(a.cmpgt(b), BVector4::new(false, false, true, false));
+        assert_eq!(a.cmpge(b), BVector4::new(false, true, true, false));
+        assert_eq!(a.cmpeq(b), BVector4::new(false, true, false, false));
+        assert_eq!(a.cmpne(b), BVector4::new(true, false, true, true));
+    }
+
+    #[test]
+    fn nan_compares_false() {
+        let a = Vector3::new(f32::NAN, 0., 0.);
+
+        assert_eq!(a.cmpeq(a), BVector3::new(false, true, true));
+        assert_eq!(a.cmpne(a), BVector3::new(true, false, false));
+        assert!(!a.cmplt(Vector3::one()).all());
+    }
+
+    #[test]
+    fn mask_reductions() {
+        let m = BVector4::new(true, false, true, true);
+
+        assert!(m.any());
+        assert!(!m.all());
+        assert_eq!(m.bitmask(), 0b1101);
+        assert!(BVector2::splat(true).all());
+        assert!(!BVector3::splat(false).any());
+        assert_eq!(!m, BVector4::new(false, true, false, false));
+        assert_eq!(m & !m, BVector4::splat(false));
+        assert_eq!(m | !m, BVector4::splat(true));
+    }
+
+    #[test]
+    fn select() {
+        let a = Vector4::new(1., 2., 3., 4.);
+        let b = Vector4::new(5., 6., 7., 8.);
+
+        assert_eq!(Vector4::select(a.cmpgt(Vector4::all(2.)), a, b), Vector4::new(5., 6., 3., 4.));
+        assert_eq!(
+            DVector2::select(BVector2::new(false, true), DVector2::zero(), DVector2::one()),
+            DVector2::new(1., 0.)
+        );
+    }
+
+    #[test]
+    fn int_vector_compares() {
+        let a = IVector3::new(-1, 0, 1);
+
+        assert_eq!(a.cmplt(IVector3::zero()).bitmask(), 0b001);
+        assert_eq!(IVector3::select(a.cmpge(IVector3::zero()), a, -a), IVector3::new(1, 0, 1));
+        assert!(UVector2::new(1, 2).cmpne(UVector2::new(1, 3)).any());
+    }
+}
@@ -47,6 +47,13 @@ mod tests {
         assert_eq!(m[3][2], expected);
     }
 
+    #[test]
+    #[should_panic]
+    pub fn matrix_out_of_bounds_index_panics() {
+        let m = Matrix4x4::identity();
+        let _ = m[4];
+    }
+
     #[test]
     pub fn matrix_ortho_test() {
         let m = Matrix4x4::ortho(0., 512., 0., 512.);
@@ -0,0 +1,84 @@
+mod tests {
+    use division_math::{
+        oct_decode, oct_decode_snorm16, oct_decode_snorm8, oct_encode, oct_encode_precise_snorm16,
+        oct_encode_precise_snorm8, oct_encode_snorm16, oct_encode_snorm8, spherical_decode,
+        spherical_encode, Vector3,
+    };
+
+    fn fibonacci_sphere(count: usize) -> impl Iterator<Item = Vector3> {
+        let golden = std::f32::consts::PI * (3. - 5f32.sqrt());
+
+        (0..count).map(move |i| {
+            let y = 1. - 2. * (i as f32 + 0.5) / count as f32;
+            let r = (1. - y * y).sqrt();
+            let phi = golden * i as f32;
+
+            Vector3::new(r * phi.cos(), y, r * phi.sin())
+        })
+    }
+
+    fn max_angle_degrees(f: impl Fn(Vector3) -> Vector3) -> f32 {
+        fibonacci_sphere(20000)
+            .map(|n| {
+                let d = f(n);
+                Vector3::cross(d, n).length().atan2(Vector3::dot(d, n)).to_degrees()
+            })
+            .fold(0., f32::max)
+    }
+
+    #[test]
+    fn oct_axes() {
+        for n in [
+            Vector3::right(),
+            -Vector3::right(),
+            Vector3::up(),
+            -Vector3::up(),
+            Vector3::forward(),
+            -Vector3::forward(),
+        ] {
+            assert!(Vector3::approx(oct_decode(oct_encode(n)), n));
+        }
+    }
+
+    #[test]
+    fn oct_float_angular_error() {
+        assert!(max_angle_degrees(|n| oct_decode(oct_encode(n))) < 0.03);
+    }
+
+    #[test]
+    fn oct_snorm16_angular_error() {
+        let plain = max_angle_degrees(|n| oct_decode_snorm16(oct_encode_snorm16(n)));
+        let precise = max_angle_degrees(|n| oct_decode_snorm16(oct_encode_precise_snorm16(n)));
+
+        assert!(plain < 0.01, "plain snorm16 error {plain}");
+        assert!(precise <= plain, "precise {precise} > plain {plain}");
+    }
+
+    #[test]
+    fn oct_snorm8_angular_error() {
+        let plain = max_angle_degrees(|n| oct_decode_snorm8(oct_encode_snorm8(n)));
+        let precise = max_angle_degrees(|n| oct_decode_snorm8(oct_encode_precise_snorm8(n)));
+
+        assert!(plain < 1.5, "plain snorm8 error {plain}");
+        assert!(precise < plain, "precise {precise} >= plain {plain}");
+    }
+
+    #[test]
+    fn spherical_round_trip() {
+        let v = Vector3::new(1., -2., 3.);
+        let s = v.to_spherical();
+
+        assert!((s.x - v.length()).abs() < 1e-5);
+        assert!((Vector3::from_spherical(s) - v).length() < 1e-5);
+        assert_eq!(Vector3::up().to_spherical(), Vector3::new(1., 0., 0.));
+    }
+
+    #[test]
+    fn spherical_encoding_angular_error() {
+        let error = max_angle_degrees(|n| spherical_decode(spherical_encode(n)));
+        assert!(error < 0.01, "spherical error {error}");
+
+        let e = spherical_encode(Vector3::new(0.3, 0.4, -0.5).normalized());
+        assert!((0. ..=1.).contains(&e.x) && (0. ..=1.).contains(&e.y));
+    }
+}
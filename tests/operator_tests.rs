@@ -0,0 +1,88 @@
+#[allow(clippy::op_ref)]
+mod tests {
+    use division_math::{DMatrix4x4, DVector3, IVector2, Matrix4x4, UVector3, Vector2, Vector3, Vector4};
+    use std::ops::{Add, Mul};
+
+    fn sum_generic<T>(a: &T, b: &T) -> T
+    where
+        for<'a> &'a T: Add<&'a T, Output = T>,
+    {
+        a + b
+    }
+
+    fn scale_generic<T>(v: &T, s: f32) -> T
+    where
+        for<'a> &'a T: Mul<f32, Output = T>,
+    {
+        v * s
+    }
+
+    #[test]
+    fn reference_ops() {
+        let a = Vector3::new(1., 2., 3.);
+        let b = Vector3::new(4., 5., 6.);
+
+        assert_eq!(&a + &b, a + b);
+        assert_eq!(&a - b, a - b);
+        assert_eq!(a * &b, a * b);
+        assert_eq!(&a / &b, a / b);
+        assert_eq!(&a % 2., a % 2.);
+        assert_eq!(sum_generic(&a, &b), Vector3::new(5., 7., 9.));
+        assert_eq!(scale_generic(&Vector4::one(), 3.), Vector4::all(3.));
+        assert_eq!(&IVector2::new(6, 3) & &IVector2::new(3, 3), IVector2::new(2, 3));
+    }
+
+    #[test]
+    fn scalar_on_left() {
+        let v = Vector2::new(2., 4.);
+
+        assert_eq!(2. * v, Vector2::new(4., 8.));
+        assert_eq!(8. / v, Vector2::new(4., 2.));
+        assert_eq!(2. * &v, v * 2.);
+        assert_eq!(0.5 * DVector3::all(2.), DVector3::one());
+        assert_eq!(12 / UVector3::new(1, 2, 3), UVector3::new(12, 6, 4));
+    }
+
+    #[test]
+    fn matrix_ops() {
+        let m = Matrix4x4::scale(Vector3::all(2.));
+        let t = Matrix4x4::translation(Vector3::new(1., 2., 3.));
+        let v = Vector4::new(1., 1., 1., 1.);
+
+        assert_eq!(m * &v, m * v);
+        assert_eq!(&m * &v, m * v);
+        assert_eq!(&m * &t, m * t);
+        assert_eq!(m + m, m * 2.);
+        assert_eq!(2. * m, m * 2.);
+        assert_eq!(m - m, Matrix4x4::zero());
+        assert_eq!(-m + m, Matrix4x4::zero());
+
+        let mut a = m;
+        a *= t;
+        assert_eq!(a, m * t);
+        a *= 0.5;
+        assert_eq!(a, m * t * 0.5);
+        a += Matrix4x4::identity();
+        a -= Matrix4x4::identity();
+        assert_eq!(a, m * t * 0.5);
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let vectors = [Vector3::new(1., 2., 3.), Vector3::new(4., 5., 6.), Vector3::all(2.)];
+
+        assert_eq!(vectors.iter().sum::<Vector3>(), Vector3::new(7., 9., 11.));
+        assert_eq!(vectors.into_iter().product::<Vector3>(), Vector3::new(8., 20., 36.));
+        assert_eq!(std::iter::empty::<IVector2>().sum::<IVector2>(), IVector2::zero());
+        assert_eq!(std::iter::empty::<Vector4>().product::<Vector4>(), Vector4::one());
+
+        let transforms = [
+            DMatrix4x4::translation(DVector3::new(1., 0., 0.)),
+            DMatrix4x4::scale(DVector3::all(3.)),
+        ];
+
+        assert_eq!(transforms.iter().product::<DMatrix4x4>(), transforms[0] * transforms[1]);
+        assert_eq!(transforms.into_iter().sum::<DMatrix4x4>(), transforms[0] + transforms[1]);
+        assert_eq!(std::iter::empty::<Matrix4x4>().product::<Matrix4x4>(), Matrix4x4::identity());
+    }
+}
@@ -0,0 +1,112 @@
+mod tests {
+    use division_math::{
+        pack_r11g11b10f, pack_rgb10a2, pack_rgb9e5, pack_rgba8_srgb, pack_snorm16, pack_snorm8,
+        pack_unorm8, unpack_r11g11b10f, unpack_rgb10a2, unpack_rgb9e5, unpack_rgba8_srgb,
+        unpack_snorm8, Color, Vector2, Vector3, Vector4,
+    };
+
+    fn samples() -> impl Iterator<Item = f32> {
+        (0..=1000).map(|i| i as f32 / 1000.)
+    }
+
+    #[test]
+    fn unorm8_round_trip_error() {
+        for v in samples() {
+            let packed = Vector4::all(v).to_unorm8();
+            let error = (Vector4::from_unorm8(packed) - Vector4::all(v)).abs().max_element();
+            assert!(error <= 0.5 / 255. + 1e-6);
+        }
+    }
+
+    #[test]
+    fn snorm8_round_trip_error() {
+        for v in samples() {
+            let v = v * 2. - 1.;
+            let packed = Vector3::all(v).to_snorm8();
+            let error = (Vector3::from_snorm8(packed) - Vector3::all(v)).abs().max_element();
+            assert!(error <= 0.5 / 127. + 1e-6);
+        }
+    }
+
+    #[test]
+    fn unorm16_and_snorm16_round_trip_error() {
+        for v in samples() {
+            let u = Vector2::new(v, 1. - v);
+            let error = (Vector2::from_unorm16(u.to_unorm16()) - u).abs().max_element();
+            assert!(error <= 0.5 / 65535. + 1e-6);
+
+            let s = Vector2::new(v * 2. - 1., 1. - v * 2.);
+            let error = (Vector2::from_snorm16(s.to_snorm16()) - s).abs().max_element();
+            assert!(error <= 0.5 / 32767. + 1e-6);
+        }
+    }
+
+    #[test]
+    fn normalized_integer_edges() {
+        assert_eq!(pack_unorm8(-1.), 0);
+        assert_eq!(pack_unorm8(2.), 255);
+        assert_eq!(pack_snorm8(-2.), -127);
+        assert_eq!(pack_snorm16(1.), i16::MAX);
+        assert_eq!(unpack_snorm8(i8::MIN), -1.);
+        assert_eq!(Vector4::new(0., 1., 0.5, 1.).to_unorm8(), [0, 255, 128, 255]);
+    }
+
+    #[test]
+    fn rgb10a2_round_trip_error() {
+        for v in samples() {
+            let c = Vector4::new(v, 1. - v, v * 0.5, 1.);
+            let error = (unpack_rgb10a2(pack_rgb10a2(c)) - c).abs().max_element();
+            assert!(error <= 0.5 / 1023. + 1e-6);
+        }
+
+        assert_eq!(pack_rgb10a2(Vector4::one()), u32::MAX);
+        assert_eq!(unpack_rgb10a2(pack_rgb10a2(Vector4::new(0., 0., 0., 0.4))).w, 1. / 3.);
+    }
+
+    #[test]
+    fn r11g11b10f_round_trip_error() {
+        for v in samples() {
+            let c = Vector3::new(v * 100., v, v * 0.01) + Vector3::all(1e-3);
+            let unpacked = unpack_r11g11b10f(pack_r11g11b10f(c));
+
+            assert!((unpacked.x - c.x).abs() <= c.x * 2f32.powi(-7));
+            assert!((unpacked.y - c.y).abs() <= c.y * 2f32.powi(-7));
+            assert!((unpacked.z - c.z).abs() <= c.z * 2f32.powi(-6));
+        }
+    }
+
+    #[test]
+    fn r11g11b10f_special_values() {
+        assert_eq!(unpack_r11g11b10f(pack_r11g11b10f(Vector3::new(1., 0.5, 2.))), Vector3::new(1., 0.5, 2.));
+        assert_eq!(unpack_r11g11b10f(pack_r11g11b10f(Vector3::all(-1.))), Vector3::zero());
+        assert_eq!(unpack_r11g11b10f(pack_r11g11b10f(Vector3::all(1e9))), Vector3::new(65024., 65024., 64512.));
+        assert!(unpack_r11g11b10f(pack_r11g11b10f(Vector3::all(f32::INFINITY))).x.is_infinite());
+        assert!(unpack_r11g11b10f(pack_r11g11b10f(Vector3::all(f32::NAN))).y.is_nan());
+    }
+
+    #[test]
+    fn rgb9e5_round_trip_error() {
+        for v in samples() {
+            let c = Vector3::new(v * 1000., v * 10., v);
+            let unpacked = unpack_rgb9e5(pack_rgb9e5(c));
+            let max = c.x.max(c.y).max(c.z);
+
+            assert!((unpacked - c).abs().max_element() <= max * 2f32.powi(-9) + 1e-6);
+        }
+
+        assert_eq!(unpack_rgb9e5(pack_rgb9e5(Vector3::new(1., 0.5, 0.))), Vector3::new(1., 0.5, 0.));
+        assert_eq!(unpack_rgb9e5(pack_rgb9e5(Vector3::all(1e9))), Vector3::all(65408.));
+    }
+
+    #[test]
+    fn rgba8_srgb_round_trip() {
+        for i in 0..=255u32 {
+            let packed = i | (255 - i) << 8 | i << 16 | 255 << 24;
+            assert_eq!(pack_rgba8_srgb(unpack_rgba8_srgb(packed)), packed);
+        }
+
+        let c = Color::new(0.5, 0.2, 1., 1.);
+        assert_eq!(c.to_rgba8_srgb().to_le_bytes(), [188, 124, 255, 255]);
+        assert!(Color::approx(Color::from_rgba8_srgb(Color::WHITE.to_rgba8_srgb()), Color::WHITE));
+    }
+}
@@ -0,0 +1,94 @@
+mod tests {
+    use division_math::{
+        f16, BVector3, Color, DMatrix4x4, DVector4, HalfVector3, IVector2, Matrix4x4, UVector3, Vector2, Vector3,
+        Vector3A, Vector4,
+    };
+    use serde::de::DeserializeOwned;
+    use serde::{Deserialize, Serialize};
+    use std::fmt::Debug;
+
+    fn round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T) {
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value, "{json}");
+
+        let bytes = bincode::serialize(&value).unwrap();
+        assert_eq!(bincode::deserialize::<T>(&bytes).unwrap(), value);
+    }
+
+    #[test]
+    fn vectors_serialize_as_arrays() {
+        assert_eq!(serde_json::to_string(&Vector2::new(1., -2.5)).unwrap(), "[1.0,-2.5]");
+        assert_eq!(serde_json::to_string(&Vector3::new(1., 2., 3.)).unwrap(), "[1.0,2.0,3.0]");
+        assert_eq!(serde_json::to_string(&IVector2::new(-1, 7)).unwrap(), "[-1,7]");
+        assert_eq!(serde_json::to_string(&UVector3::new(1, 2, 3)).unwrap(), "[1,2,3]");
+        assert_eq!(serde_json::to_string(&BVector3::new(true, false, true)).unwrap(), "[true,false,true]");
+        assert_eq!(serde_json::to_string(&Vector3A::new(1., 2., 3.)).unwrap(), "[1.0,2.0,3.0]");
+        assert_eq!(serde_json::to_string(&Color::new(1., 0.5, 0., 1.)).unwrap(), "[1.0,0.5,0.0,1.0]");
+        assert_eq!(serde_json::to_string(&f16::from_f32(0.5)).unwrap(), "0.5");
+    }
+
+    #[test]
+    fn binary_has_no_length_prefix() {
+        assert_eq!(bincode::serialize(&Vector3::new(1., 2., 3.)).unwrap().len(), 12);
+        assert_eq!(bincode::serialize(&DVector4::all(1.)).unwrap().len(), 32);
+
+        let flat: [f32; 16] = std::array::from_fn(|i| (i + 1) as f32);
+        assert_eq!(bincode::serialize(&Matrix4x4::from_cols_array(flat)).unwrap(), bincode::serialize(&flat).unwrap());
+    }
+
+    #[test]
+    fn matrix_serializes_column_major() {
+        let m = Matrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f32));
+        let json = serde_json::to_string(&m).unwrap();
+        let expected: Vec<f32> = (1..=16).map(|v| v as f32).collect();
+
+        assert_eq!(json, serde_json::to_string(&expected).unwrap());
+        assert_eq!(serde_json::from_str::<Matrix4x4>(&json).unwrap(), m);
+    }
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Node {
+        #[serde(with = "division_math::nested_columns")]
+        transform: Matrix4x4,
+        #[serde(with = "division_math::nested_columns")]
+        precise: DMatrix4x4,
+    }
+
+    #[test]
+    fn matrix_nested_columns() {
+        let node = Node {
+            transform: Matrix4x4::from_cols_array(std::array::from_fn(|i| (i + 1) as f32)),
+            precise: DMatrix4x4::identity(),
+        };
+        let json = serde_json::to_string(&node).unwrap();
+
+        assert!(json.starts_with(
+            r#"{"transform":[[1.0,2.0,3.0,4.0],[5.0,6.0,7.0,8.0],[9.0,10.0,11.0,12.0],[13.0,14.0,15.0,16.0]],"#
+        ));
+        round_trip(node);
+    }
+
+    #[test]
+    fn round_trips() {
+        round_trip(Vector2::new(0.1, -3.7));
+        round_trip(Vector3::new(f32::MAX, f32::MIN_POSITIVE, -0.));
+        round_trip(Vector4::new(1e-7, 2., -3., 4e20));
+        round_trip(DVector4::new(0.1, 0.2, 0.3, std::f64::consts::PI));
+        round_trip(IVector2::new(i32::MIN, i32::MAX));
+        round_trip(UVector3::new(0, 1, u32::MAX));
+        round_trip(BVector3::new(false, true, false));
+        round_trip(Vector3A::new(0.25, -8., 1.5));
+        round_trip(Color::new(0.2, 0.4, 0.6, 0.8));
+        round_trip(HalfVector3::new(f16::from_f32(0.1), f16::MAX, f16::MIN_POSITIVE));
+        round_trip(Matrix4x4::from_cols_array(std::array::from_fn(|i| i as f32 * 0.3 - 2.)));
+        round_trip(DMatrix4x4::identity());
+    }
+
+    #[test]
+    fn wrong_lengths_are_rejected() {
+        assert!(serde_json::from_str::<Vector3>("[1.0,2.0]").is_err());
+        assert!(serde_json::from_str::<Vector2>("[1.0,2.0,3.0]").is_err());
+        assert!(serde_json::from_str::<Matrix4x4>("[1.0,2.0,3.0,4.0]").is_err());
+        assert!(serde_json::from_str::<Vector3>(r#"{"x":1.0,"y":2.0,"z":3.0}"#).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+// Checks the Vector4 and Matrix4x4 entry points against a lane-by-lane f32 reference. Run it
+// once with the default scalar build and once per SIMD backend (`--features enable_simd` on
+// NEON, `cargo test-wasm` for simd128, `+nightly --features portable_simd` elsewhere) so every
+// backend is held to the scalar ops.
+mod tests {
+    use division_math::{BVector4, Matrix4x4, Vector4};
+    use rand::rngs::StdRng;
+    use rand::{Rng, SeedableRng};
+
+    const SPECIAL_VALUES: [f32; 12] = [
+        0., -0., 0.5, -0.5, 1.5, -2.5, 1e-40, f32::MAX, f32::MIN, f32::INFINITY, f32::NEG_INFINITY, f32::NAN,
+    ];
+
+    fn samples() -> Vec<Vector4> {
+        let mut rng = StdRng::seed_from_u64(0x5eed);
+        let lane = |rng: &mut StdRng| {
+            if rng.gen_bool(0.25) {
+                SPECIAL_VALUES[rng.gen_range(0..SPECIAL_VALUES.len())]
+            } else {
+                rng.gen_range(-1000f32..1000f32)
+            }
+        };
+
+        (0..4096)
+            .map(|_| Vector4::new(lane(&mut rng), lane(&mut rng), lane(&mut rng), lane(&mut rng)))
+            .collect()
+    }
+
+    fn lanes(v: Vector4) -> [f32; 4] {
+        [v.x, v.y, v.z, v.w]
+    }
+
+    fn map(v: Vector4, f: impl Fn(f32) -> f32) -> Vector4 {
+        let [x, y, z, w] = lanes(v);
+        Vector4::new(f(x), f(y), f(z), f(w))
+    }
+
+    fn zip(a: Vector4, b: Vector4, f: impl Fn(f32, f32) -> f32) -> Vector4 {
+        let (a, b) = (lanes(a), lanes(b));
+        Vector4::new(f(a[0], b[0]), f(a[1], b[1]), f(a[2], b[2]), f(a[3], b[3]))
+    }
+
+    fn same(a: f32, b: f32) -> bool {
+        a == b || (a.is_nan() && b.is_nan())
+    }
+
+    fn assert_same(actual: Vector4, expected: Vector4, op: &str) {
+        let all_same = lanes(actual).iter().zip(lanes(expected)).all(|(a, e)| same(*a, e));
+        assert!(all_same, "{op}: got {actual:?}, expected {expected:?}");
+    }
+
+    #[test]
+    fn arithmetic_matches_scalar() {
+        let samples = samples();
+
+        for pair in samples.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+
+            assert_same(a + b, zip(a, b, |x, y| x + y), "add");
+            assert_same(a - b, zip(a, b, |x, y| x - y), "sub");
+            assert_same(a * b, zip(a, b, |x, y| x * y), "mul");
+            assert_same(a / b, zip(a, b, |x, y| x / y), "div");
+            assert_same(a * b.y, map(a, |x| x * b.y), "mul scalar");
+            assert_same(a / b.z, map(a, |x| x / b.z), "div scalar");
+        }
+    }
+
+    #[test]
+    fn math_funcs_match_scalar() {
+        let samples = samples();
+
+        for pair in samples.windows(3) {
+            let (a, b, c) = (pair[0], pair[1], pair[2]);
+
+            assert_same(a.abs(), map(a, f32::abs), "abs");
+            assert_same(a.floor(), map(a, f32::floor), "floor");
+            assert_same(a.ceil(), map(a, f32::ceil), "ceil");
+            assert_same(a.round(), map(a, f32::round), "round");
+            assert_same(a.trunc(), map(a, f32::trunc), "trunc");
+            assert_same(a.sqrt(), map(a, f32::sqrt), "sqrt");
+            assert_same(a.min(b), zip(a, b, f32::min), "min");
+            assert_same(a.max(b), zip(a, b, f32::max), "max");
+
+            let [x, y, z, w] = lanes(a);
+            assert!(same(a.min_element(), x.min(y).min(z).min(w)), "min_element of {a:?}");
+            assert!(same(a.max_element(), x.max(y).max(z).max(w)), "max_element of {a:?}");
+
+            let fused = Vector4::new(x.mul_add(b.x, c.x), y.mul_add(b.y, c.y), z.mul_add(b.z, c.z), w.mul_add(b.w, c.w));
+            assert_same(a.mul_add(b, c), fused, "mul_add");
+        }
+    }
+
+    #[test]
+    fn compares_match_scalar() {
+        let samples = samples();
+        let mask = |a: Vector4, b: Vector4, f: fn(&f32, &f32) -> bool| {
+            BVector4::new(f(&a.x, &b.x), f(&a.y, &b.y), f(&a.z, &b.z), f(&a.w, &b.w))
+        };
+
+        for pair in samples.windows(2) {
+            let (a, b) = (pair[0], pair[1]);
+
+            assert_eq!(a.cmpeq(b), mask(a, b, f32::eq));
+            assert_eq!(a.cmpne(b), mask(a, b, f32::ne));
+            assert_eq!(a.cmplt(b), mask(a, b, f32::lt));
+            assert_eq!(a.cmple(b), mask(a, b, f32::le));
+            assert_eq!(a.cmpgt(b), mask(a, b, f32::gt));
+            assert_eq!(a.cmpge(b), mask(a, b, f32::ge));
+
+            let m = a.cmplt(b);
+            let expected = Vector4::new(
+                if m.x { a.x } else { b.x },
+                if m.y { a.y } else { b.y },
+                if m.z { a.z } else { b.z },
+                if m.w { a.w } else { b.w },
+            );
+            assert_same(Vector4::select(m, a, b), expected, "select");
+        }
+    }
+
+    #[test]
+    fn matrix_products_match_scalar() {
+        let samples = samples();
+        let transform = |m: &Matrix4x4, v: Vector4| {
+            let row = |i: usize| m[0][i] * v.x + m[1][i] * v.y + m[2][i] * v.z + m[3][i] * v.w;
+            Vector4::new(row(0), row(1), row(2), row(3))
+        };
+
+        for chunk in samples.chunks_exact(8) {
+            let a = Matrix4x4::from_columns(chunk[0], chunk[1], chunk[2], chunk[3]);
+            let b = Matrix4x4::from_columns(chunk[4], chunk[5], chunk[6], chunk[7]);
+            let product = a * b;
+
+            for i in 0..4 {
+                assert_same(product[i], transform(&a, b[i]), "matrix * matrix");
+                assert_same(a * chunk[i], transform(&a, chunk[i]), "matrix * vector");
+            }
+        }
+    }
+}
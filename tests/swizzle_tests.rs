@@ -0,0 +1,54 @@
+mod tests {
+    #[cfg(feature = "swizzle")]
+    #[test]
+    fn read_swizzles() {
+        use division_math::{IVector3, Vector2, Vector3, Vector4};
+
+        let v = Vector4::new(1., 2., 3., 4.);
+
+        assert_eq!(v.xy(), Vector2::new(1., 2.));
+        assert_eq!(v.zyx(), Vector3::new(3., 2., 1.));
+        assert_eq!(v.wzyx(), Vector4::new(4., 3., 2., 1.));
+        assert_eq!(IVector3::new(1, 2, 3).zx(), IVector3::new(3, 1, 0).xy());
+    }
+
+    #[cfg(feature = "swizzle_full")]
+    #[test]
+    fn repeating_swizzles() {
+        use division_math::{IVector3, Vector4};
+
+        let v = Vector4::new(1., 2., 3., 4.);
+
+        assert_eq!(v.wwxx(), Vector4::new(4., 4., 1., 1.));
+        assert_eq!(IVector3::new(1, 2, 3).zzy(), IVector3::new(3, 3, 2));
+    }
+
+    #[cfg(feature = "swizzle_setters")]
+    #[test]
+    fn swizzle_setters() {
+        use division_math::{IVector2, IVector3, Vector2, Vector3, Vector4};
+
+        let mut v = Vector4::new(1., 2., 3., 4.);
+        v.set_xy(Vector2::new(5., 6.));
+        assert_eq!(v, Vector4::new(5., 6., 3., 4.));
+
+        v.set_wzy(Vector3::new(7., 8., 9.));
+        assert_eq!(v, Vector4::new(5., 9., 8., 7.));
+
+        assert_eq!(v.with_x(0.), Vector4::new(0., 9., 8., 7.));
+        assert_eq!(v.with_zw(Vector2::new(1., 2.)), Vector4::new(5., 9., 1., 2.));
+        assert_eq!(IVector3::new(1, 2, 3).with_zx(IVector2::new(4, 5)), IVector3::new(5, 2, 4));
+    }
+
+    #[cfg(feature = "color_swizzles")]
+    #[test]
+    fn color_swizzles() {
+        use division_math::{Vector2, Vector3, Vector4};
+
+        let v = Vector4::new(1., 2., 3., 4.);
+
+        assert_eq!(v.rgb(), v.xyz());
+        assert_eq!(v.bgra(), Vector4::new(3., 2., 1., 4.));
+        assert_eq!(Vector3::new(1., 2., 3.).rg(), Vector2::new(1., 2.));
+    }
+}
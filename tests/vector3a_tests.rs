@@ -0,0 +1,79 @@
+mod tests {
+    use division_math::{BVector3, Vector3, Vector3A, Vector4};
+
+    #[test]
+    fn layout() {
+        assert_eq!(std::mem::size_of::<Vector3A>(), 16);
+        assert_eq!(std::mem::align_of::<Vector3A>(), 16);
+    }
+
+    #[test]
+    fn matches_vector3() {
+        let (a, b) = (Vector3::new(1.5, -2., 3.25), Vector3::new(-4., 0.5, 2.));
+        let (a_a, b_a) = (Vector3A::from(a), Vector3A::from(b));
+
+        assert_eq!(Vector3::from(a_a + b_a), a + b);
+        assert_eq!(Vector3::from(a_a - b_a), a - b);
+        assert_eq!(Vector3::from(a_a * b_a), a * b);
+        assert_eq!(Vector3::from(a_a / b_a), a / b);
+        assert_eq!(Vector3::from(a_a * 3.), a * 3.);
+        assert_eq!(Vector3::from(2. * a_a), a * 2.);
+        assert_eq!(Vector3::from(a_a / 4.), a / 4.);
+        assert_eq!(Vector3::from(-a_a), -a);
+        assert_eq!(Vector3A::dot(a_a, b_a), Vector3::dot(a, b));
+        assert_eq!(Vector3::from(Vector3A::cross(a_a, b_a)), Vector3::cross(a, b));
+        assert_eq!(a_a.length(), a.length());
+        assert_eq!(Vector3::from(a_a.normalized()), a.normalized());
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut v = Vector3A::new(1., 2., 3.);
+        v += Vector3A::one();
+        v *= 2.;
+        v -= Vector3A::new(1., 1., 1.);
+        v /= Vector3A::all(2.);
+
+        assert_eq!(v, Vector3A::new(1.5, 2.5, 3.5));
+    }
+
+    #[test]
+    fn conversions() {
+        let v = Vector3A::new(1., 2., 3.);
+
+        assert_eq!(v.to_vec4_as_point(), Vector4::new(1., 2., 3., 1.));
+        assert_eq!(v.to_vec4_as_direction(), Vector4::new(1., 2., 3., 0.));
+        assert_eq!(Vector3A::from(Vector4::new(1., 2., 3., 9.)), v);
+        assert_eq!(Vector3A::from((1., 2., 3.)), v);
+        assert!(Vector3A::approx(Vector3A::cross(Vector3A::new(1., 0., 0.), Vector3A::new(0., 1., 0.)), Vector3A::new(0., 0., 1.)));
+    }
+
+    #[test]
+    fn padding_lane_stays_zero() {
+        let v = Vector3A::new(1., -2., 3.);
+
+        assert_eq!((v / Vector3A::one()).to_vec4_as_direction().w, 0.);
+        assert_eq!((v * f32::INFINITY).to_vec4_as_direction().w, 0.);
+        assert_eq!((v / 0.).to_vec4_as_direction().w, 0.);
+        assert_eq!(v / 0., v / 0.);
+        assert_eq!(Vector3A::from(Vector4::new(1., 2., 3., f32::NAN)), Vector3A::new(1., 2., 3.));
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn generated_api() {
+        let mut v = Vector3A::new(1., 2., 3.);
+        v[1] = 5.;
+
+        assert_eq!(v[1], 5.);
+        assert_eq!(v.to_array(), [1., 5., 3.]);
+        assert_eq!(&v + &Vector3A::one(), Vector3A::new(2., 6., 4.));
+        assert_eq!([v, v].iter().sum::<Vector3A>(), v * 2.);
+        assert_eq!([v, Vector3A::all(2.)].into_iter().product::<Vector3A>(), v * 2.);
+        assert_eq!(Vector3A::new(-1.5, 2.5, -0.5).abs(), Vector3A::new(1.5, 2.5, 0.5));
+        assert_eq!(Vector3A::min(v, Vector3A::all(2.)), Vector3A::new(1., 2., 2.));
+        assert_eq!(v.max_element(), 5.);
+        assert_eq!(v.cmpgt(Vector3A::all(2.)), BVector3::new(false, true, true));
+        assert_eq!(Vector3A::lerp(Vector3A::ZERO, v, 0.5), v * 0.5);
+    }
+}
@@ -1,5 +1,5 @@
 mod tests {
-    use division_math::Vector4;
+    use division_math::{DVector2, Vector4};
 
     #[test]
     fn vector_to_scalar_mul() {
@@ -38,6 +38,28 @@ mod tests {
         assert_eq!(v.z, expected);
     }
 
+    // Checked in release builds too, indexing goes through a raw pointer.
+    #[test]
+    #[should_panic]
+    fn out_of_bounds_index_panics() {
+        let v = Vector4::new(1., 2., 3., 4.);
+        let _ = v[4];
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds_index_mut_panics() {
+        let mut v = Vector4::new(1., 2., 3., 4.);
+        v[4] = 5.;
+    }
+
+    #[test]
+    #[should_panic]
+    fn out_of_bounds_double_index_panics() {
+        let v = DVector2::new(1., 2.);
+        let _ = v[2];
+    }
+
     #[test]
     fn add_assign() {
         let origin = Vector4::new(1., 2., 3., 4.);
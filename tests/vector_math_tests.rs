@@ -0,0 +1,61 @@
+mod tests {
+    use division_math::{BVector3, BVector4, DVector2, IVector3, UVector2, Vector2, Vector3, Vector4};
+
+    #[test]
+    fn rounding_funcs() {
+        let v = Vector4::new(-1.5, -0.25, 0.5, 2.75);
+
+        assert_eq!(v.floor(), Vector4::new(-2., -1., 0., 2.));
+        assert_eq!(v.ceil(), Vector4::new(-1., -0., 1., 3.));
+        assert_eq!(v.round(), Vector4::new(-2., -0., 1., 3.));
+        assert_eq!(v.trunc(), Vector4::new(-1., -0., 0., 2.));
+        assert_eq!(v.fract(), Vector4::new(-0.5, -0.25, 0.5, 0.75));
+        assert_eq!(v.abs(), Vector4::new(1.5, 0.25, 0.5, 2.75));
+        assert_eq!(v.signum(), Vector4::new(-1., -1., 1., 1.));
+    }
+
+    #[test]
+    fn min_max() {
+        let a = Vector3::new(1., 5., -3.);
+        let b = Vector3::new(2., 0., f32::NAN);
+
+        assert_eq!(a.min(b), Vector3::new(1., 0., -3.));
+        assert_eq!(a.max(b), Vector3::new(2., 5., -3.));
+        assert_eq!(a.min_element(), -3.);
+        assert_eq!(a.max_element(), 5.);
+        assert_eq!(Vector4::new(3., -1., 7., 2.).max_element(), 7.);
+        assert_eq!(IVector3::new(4, -2, 9).min_element(), -2);
+        assert_eq!(UVector2::new(4, 9).max_element(), 9);
+        assert_eq!(IVector3::new(4, -2, 0).abs(), IVector3::new(4, 2, 0));
+    }
+
+    #[test]
+    fn arithmetic_funcs() {
+        let v = Vector4::new(1., 4., 9., 16.);
+
+        assert_eq!(v.sqrt(), Vector4::new(1., 2., 3., 4.));
+        assert_eq!(v.recip(), Vector4::new(1., 0.25, 1. / 9., 0.0625));
+        assert_eq!(Vector2::new(2., 3.).powf(2.), Vector2::new(4., 9.));
+        assert_eq!(v.mul_add(Vector4::all(2.), Vector4::one()), Vector4::new(3., 9., 19., 33.));
+        assert_eq!(Vector3::one().copysign(Vector3::new(-0., 2., -3.)), Vector3::new(-1., 1., -1.));
+        assert!(DVector2::approx(DVector2::new(0., 1.).exp().ln(), DVector2::new(0., 1.)));
+    }
+
+    #[test]
+    fn trig_funcs() {
+        let v = Vector3::new(0., std::f32::consts::FRAC_PI_2, std::f32::consts::PI);
+
+        assert!(Vector3::approx(v.sin(), Vector3::new(0., 1., v.z.sin())));
+        assert!(Vector3::approx(v.cos(), Vector3::new(1., v.y.cos(), -1.)));
+    }
+
+    #[test]
+    fn classification() {
+        let v = Vector4::new(f32::NAN, f32::INFINITY, 1., -0.);
+
+        assert_eq!(v.is_nan(), BVector4::new(true, false, false, false));
+        assert_eq!(v.is_finite(), BVector4::new(false, false, true, true));
+        assert!(Vector3::one().is_finite().all());
+        assert_eq!(Vector3::zero().recip().is_finite(), BVector3::splat(false));
+    }
+}
@@ -0,0 +1,104 @@
+mod tests {
+    use division_math::{Mask4, Mask8, Vector3, Vector3x4, Vector3x8};
+
+    fn points(count: usize, offset: f32) -> Vec<Vector3> {
+        (0..count)
+            .map(|i| {
+                let i = i as f32 + offset;
+                Vector3::new(i * 0.5 - 1., 2. - i * 0.25, i * i * 0.125 + 0.5)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn gather_scatter_round_trip() {
+        let src = points(8, 0.);
+        let wide = Vector3x8::gather(&src);
+
+        assert_eq!(wide.lane(3), src[3]);
+        assert_eq!(wide.x[5], src[5].x);
+
+        let mut dst = vec![Vector3::zero(); 9];
+        wide.scatter(&mut dst);
+
+        assert_eq!(dst[..8], src[..]);
+        assert_eq!(dst[8], Vector3::zero());
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_needs_enough_lanes() {
+        Vector3x4::gather(&points(3, 0.));
+    }
+
+    #[test]
+    fn matches_per_lane_math() {
+        let (a, b) = (points(8, 0.), points(8, 3.));
+        let (wa, wb) = (Vector3x8::gather(&a), Vector3x8::gather(&b));
+        let scales: [f32; 8] = [1., 2., 3., 4., 5., 6., 7., 8.];
+
+        let dot = Vector3x8::dot(wa, wb);
+        let length = wa.length();
+
+        for i in 0..8 {
+            assert_eq!((wa + wb).lane(i), a[i] + b[i]);
+            assert_eq!((wa - wb).lane(i), a[i] - b[i]);
+            assert_eq!((wa * wb).lane(i), a[i] * b[i]);
+            assert_eq!((wa / wb).lane(i), a[i] / b[i]);
+            assert_eq!((wa * 3.).lane(i), a[i] * 3.);
+            assert_eq!((2. * wa).lane(i), a[i] * 2.);
+            assert_eq!((wa / scales).lane(i), a[i] / scales[i]);
+            assert_eq!((-wa).lane(i), -a[i]);
+            assert_eq!(dot[i], Vector3::dot(a[i], b[i]));
+            assert_eq!(Vector3x8::cross(wa, wb).lane(i), Vector3::cross(a[i], b[i]));
+            assert_eq!(length[i], a[i].length());
+            assert_eq!(wa.normalized().lane(i), a[i].normalized());
+        }
+    }
+
+    #[test]
+    fn assign_ops() {
+        let mut v = Vector3x4::one();
+        v += Vector3x4::splat(Vector3::new(1., 2., 3.));
+        v *= 2.;
+        v -= Vector3x4::one();
+        v /= Vector3x4::splat(Vector3::all(2.));
+
+        assert_eq!(v, Vector3x4::splat(Vector3::new(1.5, 2.5, 3.5)));
+    }
+
+    #[test]
+    fn masks_and_select() {
+        let a = Vector3x8::gather(&points(8, 0.));
+        let b = Vector3x8::splat(Vector3::zero());
+
+        let mask = Mask8::cmpgt(a.x, b.x);
+        assert_eq!(mask.bitmask(), 0b1111_1000);
+        assert!(mask.any() && !mask.all());
+        assert!(mask.test(3) && !mask.test(2));
+        assert_eq!(!mask, Mask8::cmple(a.x, b.x));
+        assert_eq!(mask & !mask, Mask8::splat(false));
+        assert_eq!(mask | !mask, Mask8::splat(true));
+
+        let selected = Vector3x8::select(mask, a, b);
+        for i in 0..8 {
+            assert_eq!(selected.lane(i), if mask.test(i) { a.lane(i) } else { Vector3::zero() });
+        }
+
+        let mut m = Mask4::new([true, false, false, true]);
+        m.set(1, true);
+        m.set(0, false);
+        assert_eq!(m.bitmask(), 0b1010);
+        assert_eq!(Mask4::from_bitmask(0xff), Mask4::splat(true));
+    }
+
+    #[test]
+    fn lane_compares() {
+        let a = Vector3x4::gather(&points(4, 0.));
+        let mut b = a;
+        b.set_lane(2, Vector3::new(a.x[2], a.y[2], 100.));
+
+        assert_eq!(a.cmpeq(b), Mask4::new([true, true, false, true]));
+        assert_eq!(a.cmpne(b), Mask4::new([false, false, true, false]));
+    }
+}
@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+# Feature matrix for CI: builds the library with every swizzle feature set so a missing gate
+# in the generator or in code relying on swizzles shows up without having to test each
+# combination by hand, then without `std` (on libm) alone and with the optional integrations
+# so a std-only path or float method slipping in is caught. Extra arguments are passed to
+# every `cargo check`.
+set -euo pipefail
+cd "$(dirname "$0")/.."
+
+swizzle_sets=(
+    ""
+    "swizzle"
+    "swizzle_full"
+    "swizzle_setters"
+    "color_swizzles"
+    "swizzle,swizzle_setters"
+    "swizzle_full,swizzle_setters,color_swizzles"
+)
+
+no_std_sets=(
+    ""
+    "swizzle_full,swizzle_setters,color_swizzles"
+    "serde,bytemuck,mint,glam,nalgebra"
+)
+
+for features in "${swizzle_sets[@]}"; do
+    echo "checking swizzle features [$features]"
+    cargo check --lib --quiet --no-default-features --features "std,$features" "$@"
+done
+
+for features in "${no_std_sets[@]}"; do
+    echo "checking no_std features [$features]"
+    cargo check --lib --quiet --no-default-features --features "libm,$features" "$@"
+done
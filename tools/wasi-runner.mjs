@@ -0,0 +1,18 @@
+// Cargo runner for wasm32-wasip1: runs a test binary under Node's WASI implementation,
+// forwarding the harness arguments and exit code.
+import { readFile } from "node:fs/promises";
+import { WASI } from "node:wasi";
+import { argv, env, exit } from "node:process";
+
+const [wasmPath, ...args] = argv.slice(2);
+const wasi = new WASI({
+    version: "preview1",
+    args: [wasmPath, ...args],
+    env,
+    preopens: { "/": "/", ".": "." },
+});
+
+const module = await WebAssembly.compile(await readFile(wasmPath));
+const instance = await WebAssembly.instantiate(module, wasi.getImportObject());
+
+exit(wasi.start(instance));